@@ -0,0 +1,27 @@
+//! Cloning a large nested `ValueNoObj` against its `ValueNoObjShared` twin.
+//!
+//! A `ValueNoObj` clone copies every array and string in the tree, while a
+//! `ValueNoObjShared` clone only bumps the reference count of the root.
+
+mod common;
+
+use common::nested;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use serde_json_extensions::value_no_obj::ValueNoObjShared;
+use serde_json_extensions::ValueNoObj;
+
+fn clone(c: &mut Criterion) {
+    let value = nested();
+    let shared = ValueNoObjShared::from(&value);
+    assert_eq!(ValueNoObj::from(&shared), value);
+
+    let mut group = c.benchmark_group("clone nested");
+    group.bench_function("ValueNoObj", |b| b.iter(|| black_box(&value).clone()));
+    group.bench_function("ValueNoObjShared", |b| {
+        b.iter(|| black_box(&shared).clone())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, clone);
+criterion_main!(benches);
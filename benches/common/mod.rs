@@ -0,0 +1,24 @@
+//! Inputs shared between the benchmarks.
+
+use serde_json_extensions::ValueNoObj;
+
+/// 100 rows of 100 cells, each cell a short array mixing numbers, strings
+/// and nulls, three levels deep in all.
+pub fn nested() -> ValueNoObj {
+    (0..100)
+        .map(|row| {
+            (0..100)
+                .map(|col| {
+                    let cell: ValueNoObj = vec![
+                        ValueNoObj::from(row * 100 + col),
+                        ValueNoObj::from(col as f64 / 8.0),
+                        ValueNoObj::from("cell"),
+                        ValueNoObj::Null,
+                    ]
+                    .into();
+                    cell
+                })
+                .collect::<ValueNoObj>()
+        })
+        .collect()
+}
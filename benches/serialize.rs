@@ -0,0 +1,83 @@
+//! Serializing a large nested `ValueNoObj` to JSON text.
+//!
+//! `value_no_obj::to_vec` and `value_no_obj::to_writer` walk the array with an
+//! explicit stack and write every level into the one output buffer, while
+//! `serde_json_extensions::to_vec` goes through the generic `Serialize` impl.
+//! Before timing anything, the allocations made by one serialization along
+//! each path are printed, including writing into a buffer that is cleared and
+//! reused between runs.
+
+mod common;
+
+use common::nested;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use serde_json_extensions::value_no_obj;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn allocations<F: FnMut()>(mut f: F) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn serialize(c: &mut Criterion) {
+    let value = nested();
+
+    let expected = serde_json_extensions::to_vec(&value).unwrap();
+    assert_eq!(value_no_obj::to_vec(&value).unwrap(), expected);
+    let mut buf = Vec::new();
+    value_no_obj::to_writer(&mut buf, &value).unwrap();
+    assert_eq!(buf, expected);
+
+    println!(
+        "allocations per serialization: generic {}, to_vec {}, reused buffer {}",
+        allocations(|| drop(serde_json_extensions::to_vec(&value).unwrap())),
+        allocations(|| drop(value_no_obj::to_vec(&value).unwrap())),
+        allocations(|| {
+            buf.clear();
+            value_no_obj::to_writer(&mut buf, &value).unwrap();
+        }),
+    );
+
+    let mut group = c.benchmark_group("serialize nested");
+    group.bench_function("generic", |b| {
+        b.iter(|| serde_json_extensions::to_vec(black_box(&value)).unwrap())
+    });
+    group.bench_function("to_vec", |b| {
+        b.iter(|| value_no_obj::to_vec(black_box(&value)).unwrap())
+    });
+    group.bench_function("reused buffer", |b| {
+        b.iter(|| {
+            buf.clear();
+            value_no_obj::to_writer(&mut buf, black_box(&value)).unwrap();
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, serialize);
+criterion_main!(benches);
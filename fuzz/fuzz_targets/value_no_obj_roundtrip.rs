@@ -0,0 +1,27 @@
+//! Parses arbitrary bytes into a `ValueNoObj`, writes it back out and parses
+//! the output again, which must give the same value. Inputs that fail to
+//! parse, objects included, only need to fail without panicking.
+//!
+//! The crate is built with `float_roundtrip` here so that reparsing the
+//! shortest representation of a float is exact.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use serde_json_extensions::{value_no_obj, ValueNoObj};
+
+fuzz_target!(|data: &[u8]| {
+    let value = match ValueNoObj::from_slice(data) {
+        Ok(value) => value,
+        Err(_) => return,
+    };
+
+    let compact = value_no_obj::to_vec(&value).unwrap();
+    assert_eq!(ValueNoObj::from_slice(&compact).unwrap(), value);
+
+    let pretty = value_no_obj::to_vec_pretty(&value).unwrap();
+    assert_eq!(ValueNoObj::from_slice(&pretty).unwrap(), value);
+
+    // Displaying takes the same path as `to_vec`.
+    assert_eq!(value.to_string().as_bytes(), &compact[..]);
+});
@@ -32,6 +32,11 @@ pub struct Deserializer<R> {
     read: R,
     scratch: Vec<u8>,
     remaining_depth: u8,
+    accept_non_finite: bool,
+    non_finite_as_str: bool,
+    max_string_len: Option<usize>,
+    remaining_elements: Option<usize>,
+    integers_only: bool,
     #[cfg(feature = "float_roundtrip")]
     single_precision: bool,
     #[cfg(feature = "unbounded_depth")]
@@ -55,6 +60,11 @@ where
             read,
             scratch: Vec::new(),
             remaining_depth: 128,
+            accept_non_finite: false,
+            non_finite_as_str: false,
+            max_string_len: None,
+            remaining_elements: None,
+            integers_only: false,
             #[cfg(feature = "float_roundtrip")]
             single_precision: false,
             #[cfg(feature = "unbounded_depth")]
@@ -214,6 +224,80 @@ impl<'de, R: Read<'de>> Deserializer<R> {
         self.read.peek()
     }
 
+    /// Replaces the default nesting budget of 128. Each array or object
+    /// entered spends one unit, and reaching zero fails with
+    /// `RecursionLimitExceeded`.
+    ///
+    /// A budget of 0 is treated as 1, which already rejects every array and
+    /// object; storing 0 would underflow on the first one.
+    pub(crate) fn set_max_depth(&mut self, max_depth: u8) {
+        self.remaining_depth = max_depth.max(1);
+    }
+
+    /// Accepts the JSON5 tokens `NaN`, `Infinity` and `-Infinity` wherever a
+    /// value is expected, handing them to the visitor as unit (`null`), since
+    /// `Number` cannot represent them.
+    pub(crate) fn set_accept_non_finite(&mut self, accept: bool) {
+        self.accept_non_finite = accept;
+    }
+
+    /// Hands the tokens accepted by `set_accept_non_finite` to the visitor as
+    /// their own spelling, a string, instead of as unit.
+    pub(crate) fn set_non_finite_as_str(&mut self, as_str: bool) {
+        self.non_finite_as_str = as_str;
+    }
+
+    /// Fails with `StringTooLong` when a string handed to a visitor through
+    /// `deserialize_any` decodes to more than `max_len` bytes.
+    pub(crate) fn set_max_string_len(&mut self, max_len: usize) {
+        self.max_string_len = Some(max_len);
+    }
+
+    /// Fails with `TooManyElements` once the input has held more than
+    /// `max_elements` array elements in total, counted across every array
+    /// rather than per array.
+    pub(crate) fn set_max_elements(&mut self, max_elements: usize) {
+        self.remaining_elements = Some(max_elements);
+    }
+
+    /// Fails with `FloatNotAllowed` on any number written with a fraction or
+    /// an exponent, such as `1.0` or `1e3`. Integers too large for `u64` or
+    /// `i64` are still accepted.
+    pub(crate) fn set_integers_only(&mut self, integers_only: bool) {
+        self.integers_only = integers_only;
+    }
+
+    /// Called with the `.`, `e` or `E` that starts a number's fraction or
+    /// exponent as the next byte.
+    fn reject_float(&self) -> Result<()> {
+        if self.integers_only {
+            Err(self.peek_error(ErrorCode::FloatNotAllowed))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Fails if the next value in the input is an array or an object, for
+    /// callers that only accept scalars.
+    pub(crate) fn reject_compound(&mut self) -> Result<()> {
+        match tri!(self.parse_whitespace()) {
+            Some(b'[') => Err(self.peek_error(ErrorCode::ArrayNotSupported)),
+            Some(b'{') => Err(self.peek_error(ErrorCode::ObjectNotSupported)),
+            _ => Ok(()),
+        }
+    }
+
+    fn visit_non_finite<V>(&self, token: &'static str, visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        if self.non_finite_as_str {
+            visitor.visit_borrowed_str(token)
+        } else {
+            visitor.visit_unit()
+        }
+    }
+
     fn peek_or_null(&mut self) -> Result<u8> {
         Ok(tri!(self.peek()).unwrap_or(b'\x00'))
     }
@@ -502,8 +586,14 @@ impl<'de, R: Read<'de>> Deserializer<R> {
 
     fn parse_number(&mut self, positive: bool, significand: u64) -> Result<ParserNumber> {
         Ok(match tri!(self.peek_or_null()) {
-            b'.' => ParserNumber::F64(tri!(self.parse_decimal(positive, significand, 0))),
-            b'e' | b'E' => ParserNumber::F64(tri!(self.parse_exponent(positive, significand, 0))),
+            b'.' => {
+                tri!(self.reject_float());
+                ParserNumber::F64(tri!(self.parse_decimal(positive, significand, 0)))
+            }
+            b'e' | b'E' => {
+                tri!(self.reject_float());
+                ParserNumber::F64(tri!(self.parse_exponent(positive, significand, 0)))
+            }
             _ => {
                 if positive {
                     ParserNumber::U64(significand)
@@ -692,10 +782,12 @@ impl<'de, R: Read<'de>> Deserializer<R> {
                     self.eat_char();
                 }
                 b'.' => {
+                    tri!(self.reject_float());
                     self.eat_char();
                     return self.parse_long_decimal(positive, self.scratch.len());
                 }
                 b'e' | b'E' => {
+                    tri!(self.reject_float());
                     return self.parse_long_exponent(positive, self.scratch.len());
                 }
                 _ => {
@@ -719,9 +811,11 @@ impl<'de, R: Read<'de>> Deserializer<R> {
                     exponent += 1;
                 }
                 b'.' => {
+                    tri!(self.reject_float());
                     return self.parse_decimal(positive, significand, exponent);
                 }
                 b'e' | b'E' => {
+                    tri!(self.reject_float());
                     return self.parse_exponent(positive, significand, exponent);
                 }
                 _ => {
@@ -986,8 +1080,14 @@ impl<'de, R: Read<'de>> Deserializer<R> {
     #[cfg(feature = "arbitrary_precision")]
     fn scan_number(&mut self, buf: &mut String) -> Result<()> {
         match tri!(self.peek_or_null()) {
-            b'.' => self.scan_decimal(buf),
-            e @ (b'e' | b'E') => self.scan_exponent(e as char, buf),
+            b'.' => {
+                tri!(self.reject_float());
+                self.scan_decimal(buf)
+            }
+            e @ (b'e' | b'E') => {
+                tri!(self.reject_float());
+                self.scan_exponent(e as char, buf)
+            }
             _ => Ok(()),
         }
     }
@@ -1409,15 +1509,37 @@ impl<'de, 'a, R: Read<'de>> de::Deserializer<'de> for &'a mut Deserializer<R> {
                 tri!(self.parse_ident(b"alse"));
                 visitor.visit_bool(false)
             }
+            b'N' if self.accept_non_finite => {
+                self.eat_char();
+                tri!(self.parse_ident(b"aN"));
+                self.visit_non_finite("NaN", visitor)
+            }
+            b'I' if self.accept_non_finite => {
+                self.eat_char();
+                tri!(self.parse_ident(b"nfinity"));
+                self.visit_non_finite("Infinity", visitor)
+            }
             b'-' => {
                 self.eat_char();
-                tri!(self.parse_any_number(false)).visit(visitor)
+                if self.accept_non_finite && tri!(self.peek_or_null()) == b'I' {
+                    self.eat_char();
+                    tri!(self.parse_ident(b"nfinity"));
+                    self.visit_non_finite("-Infinity", visitor)
+                } else {
+                    tri!(self.parse_any_number(false)).visit(visitor)
+                }
             }
             b'0'..=b'9' => tri!(self.parse_any_number(true)).visit(visitor),
             b'"' => {
                 self.eat_char();
                 self.scratch.clear();
-                match tri!(self.read.parse_str(&mut self.scratch)) {
+                let s = tri!(self.read.parse_str(&mut self.scratch));
+                if let Some(max_len) = self.max_string_len {
+                    if s.len() > max_len {
+                        return Err(self.error(ErrorCode::StringTooLong));
+                    }
+                }
+                match s {
                     Reference::Borrowed(s) => visitor.visit_borrowed_str(s),
                     Reference::Copied(s) => visitor.visit_str(s),
                 }
@@ -1720,6 +1842,15 @@ impl<'de, 'a, R: Read<'de>> de::Deserializer<'de> for &'a mut Deserializer<R> {
             if name == crate::raw::TOKEN {
                 return self.deserialize_raw_value(visitor);
             }
+            if name == crate::raw::ARRAY_OR_RAW_TOKEN {
+                // Arrays are handed over element by element, anything else
+                // as its raw text, and objects not at all.
+                return match tri!(self.parse_whitespace()) {
+                    Some(b'[') => self.deserialize_seq(visitor),
+                    Some(b'{') => Err(self.peek_error(ErrorCode::ObjectNotSupported)),
+                    _ => self.deserialize_raw_value(visitor),
+                };
+            }
         }
 
         let _ = name;
@@ -1949,7 +2080,15 @@ impl<'de, 'a, R: Read<'de> + 'a> de::SeqAccess<'de> for SeqAccess<'a, R> {
 
         match peek {
             Some(b']') => Err(self.de.peek_error(ErrorCode::TrailingComma)),
-            Some(_) => Ok(Some(tri!(seed.deserialize(&mut *self.de)))),
+            Some(_) => {
+                if let Some(remaining) = &mut self.de.remaining_elements {
+                    if *remaining == 0 {
+                        return Err(self.de.peek_error(ErrorCode::TooManyElements));
+                    }
+                    *remaining -= 1;
+                }
+                Ok(Some(tri!(seed.deserialize(&mut *self.de))))
+            }
             None => Err(self.de.peek_error(ErrorCode::EofWhileParsingValue)),
         }
     }
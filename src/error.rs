@@ -51,9 +51,91 @@ impl Error {
     /// - `Category::Syntax` - input that is not syntactically valid JSON
     /// - `Category::Data` - input data that is semantically incorrect
     /// - `Category::Eof` - unexpected end of the input data
+    ///
+    /// Input that is valid JSON but holds an object where this crate's value
+    /// types cannot is `Category::Data`, whether it is found while parsing or
+    /// while converting with `to_value`.
+    ///
+    /// ```
+    /// # use std::collections::BTreeMap;
+    /// use serde_json_extensions::error::Category;
+    /// use serde_json_extensions::{value_no_obj, ValueNoObj};
+    ///
+    /// let err = r#"[1, {"a": 2}]"#.parse::<ValueNoObj>().unwrap_err();
+    /// assert_eq!(err.classify(), Category::Data);
+    /// assert_eq!((err.line(), err.column()), (1, 8));
+    ///
+    /// let err = value_no_obj::to_value(BTreeMap::from([("a", 1)])).unwrap_err();
+    /// assert_eq!(err.classify(), Category::Data);
+    ///
+    /// assert_eq!("[1, {".parse::<ValueNoObj>().unwrap_err().classify(), Category::Eof);
+    /// assert_eq!("[1, }".parse::<ValueNoObj>().unwrap_err().classify(), Category::Syntax);
+    ///
+    /// // None of these wrap a lower-level error, so there is no source to
+    /// // chain to.
+    /// use std::error::Error as _;
+    /// assert!(err.source().is_none());
+    ///
+    /// use std::fmt;
+    ///
+    /// #[derive(Debug)]
+    /// struct BadSector;
+    ///
+    /// impl fmt::Display for BadSector {
+    ///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    ///         f.write_str("bad sector")
+    ///     }
+    /// }
+    ///
+    /// impl std::error::Error for BadSector {}
+    ///
+    /// #[derive(Debug)]
+    /// struct DiskError(BadSector);
+    ///
+    /// impl fmt::Display for DiskError {
+    ///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    ///         f.write_str("disk on fire")
+    ///     }
+    /// }
+    ///
+    /// impl std::error::Error for DiskError {
+    ///     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    ///         Some(&self.0)
+    ///     }
+    /// }
+    ///
+    /// struct Failing;
+    ///
+    /// impl std::io::Read for Failing {
+    ///     fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+    ///         Err(std::io::Error::new(std::io::ErrorKind::Other, DiskError(BadSector)))
+    ///     }
+    /// }
+    ///
+    /// // An I/O error is displayed as the underlying `io::Error`, which shows
+    /// // the error it wraps, and chains on to that error's own source, as
+    /// // `serde_json` does.
+    /// let err = ValueNoObj::from_reader(Failing).unwrap_err();
+    /// assert_eq!(err.classify(), Category::Io);
+    /// assert_eq!(err.to_string(), "disk on fire");
+    /// let source = err.source().unwrap();
+    /// assert!(source.is::<BadSector>());
+    /// assert_eq!(source.to_string(), "bad sector");
+    ///
+    /// // Converting back recovers the `io::Error` and the error it wraps.
+    /// let inner = std::io::Error::from(err).into_inner().unwrap();
+    /// assert!(inner.downcast::<DiskError>().is_ok());
+    /// ```
     pub fn classify(&self) -> Category {
         match self.err.code {
-            ErrorCode::Message(_) => Category::Data,
+            ErrorCode::Message(_)
+            | ErrorCode::ArrayNotSupported
+            | ErrorCode::ObjectNotSupported
+            | ErrorCode::ObjectNotSupportedAt(_)
+            | ErrorCode::ObjectKeyNotSupported { .. }
+            | ErrorCode::StringTooLong
+            | ErrorCode::TooManyElements
+            | ErrorCode::FloatNotAllowed => Category::Data,
             ErrorCode::Io(_) => Category::Io,
             ErrorCode::EofWhileParsingList
             | ErrorCode::EofWhileParsingObject
@@ -308,6 +390,30 @@ pub(crate) enum ErrorCode {
 
     /// Encountered nesting of JSON maps and arrays more than 128 layers deep.
     RecursionLimitExceeded,
+
+    /// Encountered a JSON array where only a scalar is accepted.
+    ArrayNotSupported,
+
+    /// Encountered a JSON object, which the value types of this crate cannot
+    /// hold.
+    ObjectNotSupported,
+
+    /// Encountered a JSON object nested at the given JSON Pointer.
+    ObjectNotSupportedAt(Box<str>),
+
+    /// Encountered a JSON object, with one of its keys, nested at the given
+    /// JSON Pointer. The path is empty for an object at the top level.
+    ObjectKeyNotSupported { key: Box<str>, path: Box<str> },
+
+    /// Encountered a string longer than the configured maximum length.
+    StringTooLong,
+
+    /// Encountered more array elements than the configured maximum.
+    TooManyElements,
+
+    /// Encountered a number with a fraction or exponent where only integers
+    /// are accepted.
+    FloatNotAllowed,
 }
 
 impl Error {
@@ -333,6 +439,42 @@ impl Error {
         }
     }
 
+    /// Records that the object behind an "objects are not supported" error
+    /// was found inside array element `index`, so the error's path grows by
+    /// one level as it propagates out of each enclosing array. Any other
+    /// error is returned unchanged.
+    #[cold]
+    pub(crate) fn object_in_element(mut self, index: usize) -> Self {
+        let mut path = String::from("/");
+        path.push_str(itoa::Buffer::new().format(index));
+        match &mut self.err.code {
+            ErrorCode::ObjectNotSupported => {}
+            ErrorCode::ObjectNotSupportedAt(rest) => path.push_str(rest),
+            ErrorCode::ObjectKeyNotSupported { path: rest, .. } => {
+                path.push_str(rest);
+                *rest = path.into_boxed_str();
+                return self;
+            }
+            _ => return self,
+        }
+        self.err.code = ErrorCode::ObjectNotSupportedAt(path.into_boxed_str());
+        self
+    }
+
+    /// The "objects are not supported" error for an object that was caught
+    /// holding `key`, so the message can say which entry gave it away.
+    #[cold]
+    pub(crate) fn object_key_not_supported(key: String) -> Self {
+        Error::syntax(
+            ErrorCode::ObjectKeyNotSupported {
+                key: key.into_boxed_str(),
+                path: Box::from(""),
+            },
+            0,
+            0,
+        )
+    }
+
     #[cold]
     pub(crate) fn fix_position<F>(self, f: F) -> Self
     where
@@ -382,6 +524,21 @@ impl Display for ErrorCode {
             ErrorCode::TrailingCharacters => f.write_str("trailing characters"),
             ErrorCode::UnexpectedEndOfHexEscape => f.write_str("unexpected end of hex escape"),
             ErrorCode::RecursionLimitExceeded => f.write_str("recursion limit exceeded"),
+            ErrorCode::ArrayNotSupported => f.write_str("arrays are not supported"),
+            ErrorCode::ObjectNotSupported => f.write_str(OBJECT_NOT_SUPPORTED),
+            ErrorCode::ObjectNotSupportedAt(path) => {
+                write!(f, "{} at {}", OBJECT_NOT_SUPPORTED, path)
+            }
+            ErrorCode::ObjectKeyNotSupported { key, path } => {
+                tri!(f.write_str(OBJECT_NOT_SUPPORTED));
+                if !path.is_empty() {
+                    tri!(write!(f, " at {}", path));
+                }
+                write!(f, " (key {:?})", key)
+            }
+            ErrorCode::StringTooLong => f.write_str("string exceeds maximum length"),
+            ErrorCode::TooManyElements => f.write_str("too many array elements"),
+            ErrorCode::FloatNotAllowed => f.write_str("floating point numbers are not allowed"),
         }
     }
 }
@@ -478,16 +635,53 @@ impl<'a> Display for JsonUnexpected<'a> {
     }
 }
 
+const OBJECT_NOT_SUPPORTED: &str = "objects are not supported";
+
+/// The error for a JSON object reaching a type that cannot hold one.
+///
+/// Visitors only get to build their deserializer's error type through
+/// `de::Error::custom`, so this goes through it too; when `E` is this crate's
+/// `Error`, `make_error` recognizes the message and restores
+/// `ErrorCode::ObjectNotSupported`. Every site rejecting an object uses this,
+/// keeping the message the same everywhere.
+///
+/// ```
+/// # use std::collections::BTreeMap;
+/// use serde_json_extensions::{value_no_obj, value_no_obj_or_arr, ValueNoObj, ValueNoObjOrArr};
+///
+/// let mut map = BTreeMap::new();
+/// map.insert("a", 1);
+///
+/// let errors = [
+///     serde_json_extensions::from_str::<ValueNoObj>(r#"[{"a":1}]"#).unwrap_err(),
+///     serde_json_extensions::from_str::<ValueNoObjOrArr>(r#"{"a":1}"#).unwrap_err(),
+///     serde_json_extensions::from_str::<ValueNoObjOrArr>("{}").unwrap_err(),
+///     value_no_obj::to_value(&map).unwrap_err(),
+///     value_no_obj_or_arr::to_value(&map).unwrap_err(),
+/// ];
+/// for err in &errors {
+///     assert!(err.is_data());
+///     assert!(err.to_string().starts_with("objects are not supported"));
+/// }
+/// assert_eq!(errors[0].to_string(), "objects are not supported at line 1 column 5");
+/// assert_eq!(errors[3].to_string(), "objects are not supported (key \"a\")");
+/// ```
+#[cold]
+pub(crate) fn object_not_supported<E: de::Error>() -> E {
+    E::custom(OBJECT_NOT_SUPPORTED)
+}
+
 // Parse our own error message that looks like "{} at line {} column {}" to work
 // around erased-serde round-tripping the error through de::Error::custom.
 fn make_error(mut msg: String) -> Error {
     let (line, column) = parse_line_col(&mut msg).unwrap_or((0, 0));
+    let code = if msg == OBJECT_NOT_SUPPORTED {
+        ErrorCode::ObjectNotSupported
+    } else {
+        ErrorCode::Message(msg.into_boxed_str())
+    };
     Error {
-        err: Box::new(ErrorImpl {
-            code: ErrorCode::Message(msg.into_boxed_str()),
-            line,
-            column,
-        }),
+        err: Box::new(ErrorImpl { code, line, column }),
     }
 }
 
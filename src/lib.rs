@@ -430,6 +430,7 @@ mod io;
 mod iter;
 #[cfg(feature = "float_roundtrip")]
 mod lexical;
+/// The JSON number type, along with its internal representation.
 pub mod number;
 mod read;
 
@@ -301,3 +301,137 @@ macro_rules! json_unexpected {
 macro_rules! json_expect_expr_comma {
     ($e:expr , $($tt:tt)*) => {};
 }
+
+/// Construct a [`ValueNoObj`] from a JSON literal.
+///
+/// Accepts the same syntax as [`json!`] for `null`, `true`, `false`, numbers,
+/// strings and nested arrays. Interpolated expressions must implement Serde's
+/// `Serialize` trait and serialize to something other than a map.
+///
+/// ```
+/// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+/// #
+/// let code = 200;
+/// let value = value_no_obj!([null, true, code, "serde", [1.5, []],]);
+///
+/// assert_eq!(value[0], ValueNoObj::Null);
+/// assert_eq!(value[2], 200);
+/// assert_eq!(value[4][0], 1.5);
+/// ```
+///
+/// Object literals are rejected at compile time, at any depth, so both
+/// `value_no_obj!({ "code": 200 })` and `value_no_obj!([1, [2, {}]])` fail
+/// with "value_no_obj! does not support objects". The UI tests under
+/// `tests/ui` check the exact error.
+///
+/// [`ValueNoObj`]: crate::ValueNoObj
+/// [`json!`]: crate::json
+#[macro_export]
+macro_rules! value_no_obj {
+    // Hide distracting implementation details from the generated rustdoc.
+    ($($json:tt)+) => {
+        $crate::value_no_obj_internal!($($json)+)
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! value_no_obj_internal {
+    //////////////////////////////////////////////////////////////////////////
+    // TT muncher for parsing the inside of an array [...]. Produces a vec![...]
+    // of the elements.
+    //
+    // Must be invoked as: value_no_obj_internal!(@array [] $($tt)*)
+    //////////////////////////////////////////////////////////////////////////
+
+    // Done with trailing comma.
+    (@array [$($elems:expr,)*]) => {
+        $crate::__private::vec![$($elems,)*]
+    };
+
+    // Done without trailing comma.
+    (@array [$($elems:expr),*]) => {
+        $crate::__private::vec![$($elems),*]
+    };
+
+    // Next element is `null`.
+    (@array [$($elems:expr,)*] null $($rest:tt)*) => {
+        $crate::value_no_obj_internal!(@array [$($elems,)* $crate::value_no_obj_internal!(null)] $($rest)*)
+    };
+
+    // Next element is `true`.
+    (@array [$($elems:expr,)*] true $($rest:tt)*) => {
+        $crate::value_no_obj_internal!(@array [$($elems,)* $crate::value_no_obj_internal!(true)] $($rest)*)
+    };
+
+    // Next element is `false`.
+    (@array [$($elems:expr,)*] false $($rest:tt)*) => {
+        $crate::value_no_obj_internal!(@array [$($elems,)* $crate::value_no_obj_internal!(false)] $($rest)*)
+    };
+
+    // Next element is an array.
+    (@array [$($elems:expr,)*] [$($array:tt)*] $($rest:tt)*) => {
+        $crate::value_no_obj_internal!(@array [$($elems,)* $crate::value_no_obj_internal!([$($array)*])] $($rest)*)
+    };
+
+    // Next element is a map.
+    (@array [$($elems:expr,)*] {$($map:tt)*} $($rest:tt)*) => {
+        $crate::value_no_obj_internal!({$($map)*})
+    };
+
+    // Next element is an expression followed by comma.
+    (@array [$($elems:expr,)*] $next:expr, $($rest:tt)*) => {
+        $crate::value_no_obj_internal!(@array [$($elems,)* $crate::value_no_obj_internal!($next),] $($rest)*)
+    };
+
+    // Last element is an expression with no trailing comma.
+    (@array [$($elems:expr,)*] $last:expr) => {
+        $crate::value_no_obj_internal!(@array [$($elems,)* $crate::value_no_obj_internal!($last)])
+    };
+
+    // Comma after the most recent element.
+    (@array [$($elems:expr),*] , $($rest:tt)*) => {
+        $crate::value_no_obj_internal!(@array [$($elems,)*] $($rest)*)
+    };
+
+    // Unexpected token after most recent element.
+    (@array [$($elems:expr),*] $unexpected:tt $($rest:tt)*) => {
+        $crate::json_unexpected!($unexpected)
+    };
+
+    //////////////////////////////////////////////////////////////////////////
+    // The main implementation.
+    //
+    // Must be invoked as: value_no_obj_internal!($($json)+)
+    //////////////////////////////////////////////////////////////////////////
+
+    (null) => {
+        $crate::ValueNoObj::Null
+    };
+
+    (true) => {
+        $crate::ValueNoObj::Bool(true)
+    };
+
+    (false) => {
+        $crate::ValueNoObj::Bool(false)
+    };
+
+    ([]) => {
+        $crate::ValueNoObj::Array($crate::__private::vec![])
+    };
+
+    ([ $($tt:tt)+ ]) => {
+        $crate::ValueNoObj::Array($crate::value_no_obj_internal!(@array [] $($tt)+))
+    };
+
+    ({ $($tt:tt)* }) => {
+        compile_error!("value_no_obj! does not support objects")
+    };
+
+    // Any Serialize type: numbers, strings, struct literals, variables etc.
+    // Must be below every other rule.
+    ($other:expr) => {
+        $crate::value_no_obj::to_value(&$other).unwrap()
+    };
+}
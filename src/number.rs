@@ -6,6 +6,7 @@ use crate::error::ErrorCode;
 use alloc::borrow::ToOwned;
 #[cfg(feature = "arbitrary_precision")]
 use alloc::string::{String, ToString};
+use core::cmp::Ordering;
 use core::fmt::{self, Debug, Display};
 #[cfg(not(feature = "arbitrary_precision"))]
 use core::hash::{Hash, Hasher};
@@ -20,12 +21,16 @@ pub(crate) const TOKEN: &str = "$serde_json::private::Number";
 /// Represents a JSON number, whether integer or floating point.
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Number {
+    /// The stored representation, which depends on the
+    /// `arbitrary_precision` feature.
     pub n: N,
 }
 
 #[cfg(not(feature = "arbitrary_precision"))]
+/// An integer or a finite float, as stored without `arbitrary_precision`.
 #[derive(Copy, Clone)]
 pub enum N {
+    /// Always greater than or equal to zero.
     PosInt(u64),
     /// Always less than zero.
     NegInt(i64),
@@ -69,6 +74,7 @@ impl Hash for N {
     }
 }
 
+/// The number's original text, as stored with `arbitrary_precision`.
 #[cfg(feature = "arbitrary_precision")]
 pub type N = String;
 
@@ -225,6 +231,71 @@ impl Number {
         self.n.parse().ok()
     }
 
+    /// If the `Number` is an integer, represent it as i128 if possible.
+    /// Returns None otherwise.
+    ///
+    /// Without the `arbitrary_precision` feature every integer fits, since
+    /// the parser only keeps integers that fit in an `i64` or `u64`. With the
+    /// feature, integers up to the full `i128` range are recovered from their
+    /// original text.
+    ///
+    /// ```
+    /// # use serde_json_extensions::Number;
+    /// #
+    /// assert_eq!(Number::from(u64::MAX).as_i128(), Some(u64::MAX as i128));
+    /// assert_eq!(Number::from(-64).as_i128(), Some(-64));
+    /// assert_eq!(Number::from_f64(256.0).unwrap().as_i128(), None);
+    ///
+    /// let big: Number = "-18446744073709551616".parse().unwrap();
+    /// if cfg!(feature = "arbitrary_precision") {
+    ///     assert_eq!(big.as_i128(), Some(-(u64::MAX as i128) - 1));
+    /// } else {
+    ///     assert_eq!(big.as_i128(), None);
+    /// }
+    /// ```
+    #[inline]
+    pub fn as_i128(&self) -> Option<i128> {
+        #[cfg(not(feature = "arbitrary_precision"))]
+        match self.n {
+            N::PosInt(n) => Some(n as i128),
+            N::NegInt(n) => Some(n as i128),
+            N::Float(_) => None,
+        }
+        #[cfg(feature = "arbitrary_precision")]
+        self.n.parse().ok()
+    }
+
+    /// If the `Number` is an integer, represent it as u128 if possible.
+    /// Returns None otherwise.
+    ///
+    /// Without the `arbitrary_precision` feature this agrees with
+    /// [`as_u64`](Number::as_u64). With the feature, integers up to
+    /// `u128::MAX` are recovered from their original text.
+    ///
+    /// ```
+    /// # use serde_json_extensions::Number;
+    /// #
+    /// assert_eq!(Number::from(64).as_u128(), Some(64));
+    /// assert_eq!(Number::from(-64).as_u128(), None);
+    ///
+    /// let big: Number = "340282366920938463463374607431768211455".parse().unwrap();
+    /// if cfg!(feature = "arbitrary_precision") {
+    ///     assert_eq!(big.as_u128(), Some(u128::MAX));
+    /// } else {
+    ///     assert_eq!(big.as_u128(), None);
+    /// }
+    /// ```
+    #[inline]
+    pub fn as_u128(&self) -> Option<u128> {
+        #[cfg(not(feature = "arbitrary_precision"))]
+        match self.n {
+            N::PosInt(n) => Some(n as u128),
+            N::NegInt(_) | N::Float(_) => None,
+        }
+        #[cfg(feature = "arbitrary_precision")]
+        self.n.parse().ok()
+    }
+
     /// Represents the number as f64 if possible. Returns None otherwise.
     ///
     /// ```
@@ -248,6 +319,106 @@ impl Number {
         self.n.parse::<f64>().ok().filter(|float| float.is_finite())
     }
 
+    /// Represents the number as f64 only if no precision is lost. Returns
+    /// None otherwise.
+    ///
+    /// Unlike [`as_f64`](Number::as_f64), an integer that `f64` cannot hold
+    /// exactly, such as 2<sup>53</sup> + 1, gives `None`. Every integer of
+    /// magnitude up to 2<sup>53</sup> converts, as do larger ones that happen
+    /// to be representable. Floats are returned as parsed.
+    ///
+    /// ```
+    /// # use serde_json_extensions::Number;
+    /// #
+    /// let max_safe = 1u64 << 53;
+    /// assert_eq!(Number::from(max_safe).as_f64_lossless(), Some(max_safe as f64));
+    /// assert_eq!(Number::from(max_safe + 1).as_f64_lossless(), None);
+    /// assert_eq!(Number::from(max_safe + 2).as_f64_lossless(), Some((max_safe + 2) as f64));
+    /// assert_eq!(Number::from(-(max_safe as i64) - 1).as_f64_lossless(), None);
+    /// assert_eq!(Number::from(u64::MAX).as_f64_lossless(), None);
+    ///
+    /// assert_eq!(Number::from(-7).as_f64_lossless(), Some(-7.0));
+    /// assert_eq!(Number::from_f64(0.5).unwrap().as_f64_lossless(), Some(0.5));
+    ///
+    /// // `as_f64` rounds where `as_f64_lossless` refuses.
+    /// assert_eq!(Number::from(max_safe + 1).as_f64(), Some(max_safe as f64));
+    /// ```
+    pub fn as_f64_lossless(&self) -> Option<f64> {
+        #[cfg(not(feature = "arbitrary_precision"))]
+        match self.n {
+            N::PosInt(n) => i128_to_f64_exact(n as i128),
+            N::NegInt(n) => i128_to_f64_exact(n as i128),
+            N::Float(n) => Some(n),
+        }
+        #[cfg(feature = "arbitrary_precision")]
+        if let Some(i) = self.as_i128() {
+            i128_to_f64_exact(i)
+        } else if let Some(u) = self.as_u128() {
+            // Every u128 outside the i128 range is at least 2^127; guard
+            // against u128::MAX rounding up to 2^128 and saturating back.
+            let float = u as f64;
+            if float < 340282366920938463463374607431768211456.0 && float as u128 == u {
+                Some(float)
+            } else {
+                None
+            }
+        } else if self.is_f64() {
+            self.as_f64()
+        } else {
+            None
+        }
+    }
+
+    /// Orders numbers by numeric value, consistently with `Eq`.
+    ///
+    /// Integers are compared exactly against each other and against floats.
+    /// Because `1` and `1.0` are not equal as `Number`s, ties in value are
+    /// broken by putting the integer first.
+    #[cfg(not(feature = "arbitrary_precision"))]
+    pub(crate) fn total_cmp(&self, other: &Self) -> Ordering {
+        fn int(n: &N) -> Option<i128> {
+            match *n {
+                N::PosInt(i) => Some(i as i128),
+                N::NegInt(i) => Some(i as i128),
+                N::Float(_) => None,
+            }
+        }
+
+        // Exact comparison of a finite float against an integer. Rounding
+        // `i` to f64 is monotonic, so the float comparison is only
+        // inconclusive when the rounded values tie, in which case `f` is an
+        // integer well within i128 range.
+        fn cmp_float_int(f: f64, i: i128) -> Ordering {
+            match f.partial_cmp(&(i as f64)) {
+                Some(Ordering::Equal) | None => (f as i128).cmp(&i),
+                Some(ordering) => ordering,
+            }
+        }
+
+        match (&self.n, &other.n) {
+            (N::Float(a), N::Float(b)) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+            (N::Float(a), b) => cmp_float_int(*a, int(b).unwrap_or(0)).then(Ordering::Greater),
+            (a, N::Float(b)) => cmp_float_int(*b, int(a).unwrap_or(0))
+                .reverse()
+                .then(Ordering::Less),
+            (a, b) => int(a).cmp(&int(b)),
+        }
+    }
+
+    /// Orders numbers by numeric value, consistently with `Eq`.
+    ///
+    /// The decimal text of both numbers is compared exactly, however large,
+    /// small or precise, so nothing is lost to an `f64` approximation.
+    /// Numerically equal numbers with different text, such as `1` and `1.0`,
+    /// are then ordered by their text so that only identical representations
+    /// compare equal.
+    #[cfg(feature = "arbitrary_precision")]
+    pub(crate) fn total_cmp(&self, other: &Self) -> Ordering {
+        Decimal::parse(&self.n)
+            .numeric_cmp(&Decimal::parse(&other.n))
+            .then_with(|| self.n.cmp(&other.n))
+    }
+
     /// Converts a finite `f64` to a `Number`. Infinite or NaN values are not JSON
     /// numbers.
     ///
@@ -317,6 +488,45 @@ impl Number {
         self.n.parse::<f32>().ok().filter(|float| float.is_finite())
     }
 
+    /// The integer exactly if it fits in `u64` or `i64`, or with
+    /// `arbitrary_precision`, and otherwise the nearest `f64`.
+    pub(crate) fn from_i128_lossy(i: i128) -> Number {
+        #[cfg(feature = "arbitrary_precision")]
+        {
+            Number::from(i)
+        }
+        #[cfg(not(feature = "arbitrary_precision"))]
+        {
+            if let Ok(u) = u64::try_from(i) {
+                Number::from(u)
+            } else if let Ok(i) = i64::try_from(i) {
+                Number::from(i)
+            } else {
+                Number {
+                    n: N::Float(i as f64),
+                }
+            }
+        }
+    }
+
+    /// The integer exactly if it fits in `u64`, or with
+    /// `arbitrary_precision`, and otherwise the nearest `f64`.
+    pub(crate) fn from_u128_lossy(u: u128) -> Number {
+        #[cfg(feature = "arbitrary_precision")]
+        {
+            Number::from(u)
+        }
+        #[cfg(not(feature = "arbitrary_precision"))]
+        {
+            match u64::try_from(u) {
+                Ok(u) => Number::from(u),
+                Err(_) => Number {
+                    n: N::Float(u as f64),
+                },
+            }
+        }
+    }
+
     pub(crate) fn from_f32(f: f32) -> Option<Number> {
         if f.is_finite() {
             let n = {
@@ -344,6 +554,118 @@ impl Number {
     }
 }
 
+/// The parts of a number's decimal text, for comparing numbers exactly
+/// under `arbitrary_precision`.
+///
+/// The value is `0.DDD... × 10^exp`, where `DDD...` are the digits of `int`
+/// and `frac` run together with leading zeros dropped.
+#[cfg(feature = "arbitrary_precision")]
+struct Decimal<'a> {
+    negative: bool,
+    int: &'a str,
+    frac: &'a str,
+    exp: i64,
+}
+
+#[cfg(feature = "arbitrary_precision")]
+impl<'a> Decimal<'a> {
+    fn parse(s: &'a str) -> Self {
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+        let (mantissa, exp) = match s.find(['e', 'E']) {
+            Some(i) => (&s[..i], &s[i + 1..]),
+            None => (s, ""),
+        };
+        let (int, frac) = match mantissa.find('.') {
+            Some(i) => (&mantissa[..i], &mantissa[i + 1..]),
+            None => (mantissa, ""),
+        };
+
+        // Saturating, so an absurdly long exponent still orders sensibly.
+        let (exp_negative, exp) = match exp.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, exp.strip_prefix('+').unwrap_or(exp)),
+        };
+        let mut e: i64 = 0;
+        for digit in exp.chars().filter_map(|c| c.to_digit(10)) {
+            e = e.saturating_mul(10).saturating_add(digit as i64);
+        }
+        if exp_negative {
+            e = -e;
+        }
+
+        let mut decimal = Decimal {
+            negative,
+            int,
+            frac,
+            exp: 0,
+        };
+        let leading_zeros = decimal.digits().take_while(|&d| d == b'0').count();
+        decimal.exp = (int.len() as i64 - leading_zeros as i64).saturating_add(e);
+        decimal
+    }
+
+    fn digits(&self) -> impl Iterator<Item = u8> + 'a {
+        self.int.bytes().chain(self.frac.bytes())
+    }
+
+    fn significant_digits(&self) -> impl Iterator<Item = u8> + 'a {
+        self.digits().skip_while(|&d| d == b'0')
+    }
+
+    fn is_zero(&self) -> bool {
+        self.digits().all(|d| d == b'0')
+    }
+
+    fn magnitude_cmp(&self, other: &Self) -> Ordering {
+        self.exp.cmp(&other.exp).then_with(|| {
+            let mut a = self.significant_digits();
+            let mut b = other.significant_digits();
+            loop {
+                match (a.next(), b.next()) {
+                    (Some(x), Some(y)) if x == y => {}
+                    (Some(x), Some(y)) => return x.cmp(&y),
+                    // Whichever runs out first is smaller, unless all that
+                    // is left of the other is trailing zeros.
+                    (Some(x), None) => {
+                        return if x == b'0' && a.all(|d| d == b'0') {
+                            Ordering::Equal
+                        } else {
+                            Ordering::Greater
+                        };
+                    }
+                    (None, Some(y)) => {
+                        return if y == b'0' && b.all(|d| d == b'0') {
+                            Ordering::Equal
+                        } else {
+                            Ordering::Less
+                        };
+                    }
+                    (None, None) => return Ordering::Equal,
+                }
+            }
+        })
+    }
+
+    fn numeric_cmp(&self, other: &Self) -> Ordering {
+        match (self.is_zero(), other.is_zero()) {
+            (true, true) => Ordering::Equal,
+            (true, false) if other.negative => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, true) if self.negative => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => match (self.negative, other.negative) {
+                (false, true) => Ordering::Greater,
+                (true, false) => Ordering::Less,
+                (false, false) => self.magnitude_cmp(other),
+                (true, true) => self.magnitude_cmp(other).reverse(),
+            },
+        }
+    }
+}
+
 impl Display for Number {
     #[cfg(not(feature = "arbitrary_precision"))]
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -481,8 +803,11 @@ impl<'de> de::Deserialize<'de> for NumberKey {
     }
 }
 
+/// Deserializes a `Number` from its text, as handed over by a deserializer
+/// under `arbitrary_precision`.
 #[cfg(feature = "arbitrary_precision")]
 pub struct NumberFromString {
+    /// The parsed number.
     pub value: Number,
 }
 
@@ -799,3 +1124,12 @@ impl Number {
         Unexpected::Other("number")
     }
 }
+
+fn i128_to_f64_exact(i: i128) -> Option<f64> {
+    let float = i as f64;
+    if float as i128 == i {
+        Some(float)
+    } else {
+        None
+    }
+}
@@ -291,6 +291,11 @@ where
 
 pub const TOKEN: &str = "$serde_json::private::RawValue";
 
+/// Asks this crate's `Deserializer` for the next array as a sequence, or for
+/// any other value as a `RawValue`, so scalars keep their original text
+/// without the arrays around them being buffered and parsed again.
+pub(crate) const ARRAY_OR_RAW_TOKEN: &str = "$serde_json::private::ArrayOrRawValue";
+
 impl Serialize for RawValue {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
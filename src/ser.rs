@@ -2048,7 +2048,11 @@ impl<'a> Formatter for PrettyFormatter<'a> {
     }
 }
 
-fn format_escaped_str<W, F>(writer: &mut W, formatter: &mut F, value: &str) -> io::Result<()>
+pub(crate) fn format_escaped_str<W, F>(
+    writer: &mut W,
+    formatter: &mut F,
+    value: &str,
+) -> io::Result<()>
 where
     W: ?Sized + io::Write,
     F: ?Sized + Formatter,
@@ -2130,6 +2134,11 @@ static ESCAPE: [u8; 256] = [
 ///
 /// Serialization guarantees it only feeds valid UTF-8 sequences to the writer.
 ///
+/// Serialization recurses once per level of nesting, so a deeply nested
+/// [`ValueNoObj`](crate::ValueNoObj) can overflow the stack. Use
+/// [`value_no_obj::to_writer`](crate::value_no_obj::to_writer) instead, which
+/// does not recurse.
+///
 /// # Errors
 ///
 /// Serialization can fail if `T`'s implementation of `Serialize` decides to
@@ -2150,6 +2159,11 @@ where
 ///
 /// Serialization guarantees it only feeds valid UTF-8 sequences to the writer.
 ///
+/// Serialization recurses once per level of nesting, so a deeply nested
+/// [`ValueNoObj`](crate::ValueNoObj) can overflow the stack. Use
+/// [`value_no_obj::to_writer_pretty`](crate::value_no_obj::to_writer_pretty)
+/// instead, which does not recurse.
+///
 /// # Errors
 ///
 /// Serialization can fail if `T`'s implementation of `Serialize` decides to
@@ -2167,6 +2181,11 @@ where
 
 /// Serialize the given data structure as a JSON byte vector.
 ///
+/// Serialization recurses once per level of nesting, so a deeply nested
+/// [`ValueNoObj`](crate::ValueNoObj) can overflow the stack. Use
+/// [`value_no_obj::to_vec`](crate::value_no_obj::to_vec) instead, which does
+/// not recurse.
+///
 /// # Errors
 ///
 /// Serialization can fail if `T`'s implementation of `Serialize` decides to
@@ -2183,6 +2202,11 @@ where
 
 /// Serialize the given data structure as a pretty-printed JSON byte vector.
 ///
+/// Serialization recurses once per level of nesting, so a deeply nested
+/// [`ValueNoObj`](crate::ValueNoObj) can overflow the stack. Use
+/// [`value_no_obj::to_vec_pretty`](crate::value_no_obj::to_vec_pretty) instead,
+/// which does not recurse.
+///
 /// # Errors
 ///
 /// Serialization can fail if `T`'s implementation of `Serialize` decides to
@@ -2199,6 +2223,10 @@ where
 
 /// Serialize the given data structure as a String of JSON.
 ///
+/// Serialization recurses once per level of nesting, so a deeply nested
+/// [`ValueNoObj`](crate::ValueNoObj) can overflow the stack. Use its `Display`
+/// impl, `value.to_string()`, instead, which does not recurse.
+///
 /// # Errors
 ///
 /// Serialization can fail if `T`'s implementation of `Serialize` decides to
@@ -2218,6 +2246,10 @@ where
 
 /// Serialize the given data structure as a pretty-printed String of JSON.
 ///
+/// Serialization recurses once per level of nesting, so a deeply nested
+/// [`ValueNoObj`](crate::ValueNoObj) can overflow the stack. Use its alternate
+/// `Display` impl, `format!("{:#}", value)`, instead, which does not recurse.
+///
 /// # Errors
 ///
 /// Serialization can fail if `T`'s implementation of `Serialize` decides to
@@ -2,7 +2,9 @@ use alloc::string::String;
 #[cfg(feature = "raw_value")]
 use alloc::string::ToString;
 use alloc::vec::{self, Vec};
+use core::cmp;
 use core::fmt;
+use core::mem;
 use core::slice;
 use core::str::FromStr;
 
@@ -14,10 +16,32 @@ use serde::de::{
 #[cfg(feature = "arbitrary_precision")]
 use crate::number::NumberFromString;
 
-use crate::error::Error;
+use crate::error::{object_not_supported, Error};
 use crate::number::Number;
-use crate::value_no_obj::ValueNoObj;
-
+use crate::value_no_obj::{NonFinite, ParseOptions, ValueNoObj};
+
+/// Scalars become the matching variant and sequences become `Array`,
+/// recursively, so nested JSON arrays round-trip through `ValueNoObj`. A map
+/// at any depth fails with the "objects are not supported" error.
+///
+/// ```
+/// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+/// #
+/// for input in ["[]", "[[]]", r#"[1,[2.5,["x",[null,true]]],[[[]]]]"#, r#""top""#] {
+///     let v: ValueNoObj = serde_json_extensions::from_str(input).unwrap();
+///     assert_eq!(serde_json_extensions::to_string(&v).unwrap(), input);
+///
+///     let copy: ValueNoObj = serde_json_extensions::value_no_obj::from_value(v.clone()).unwrap();
+///     assert_eq!(copy, v);
+/// }
+///
+/// let v: ValueNoObj = serde_json_extensions::from_str("[ 1 , [ [ -2 ] ] ]").unwrap();
+/// assert_eq!(v, value_no_obj!([1, [[-2]]]));
+///
+/// let err = serde_json_extensions::from_str::<ValueNoObj>(r#"[[1], [{"a": 2}]]"#).unwrap_err();
+/// assert!(err.is_data());
+/// assert!(err.to_string().starts_with("objects are not supported"));
+/// ```
 impl<'de> Deserialize<'de> for ValueNoObj {
     #[inline]
     fn deserialize<D>(deserializer: D) -> Result<ValueNoObj, D::Error>
@@ -68,6 +92,52 @@ impl<'de> Deserialize<'de> for ValueNoObj {
                 Ok(ValueNoObj::String(value))
             }
 
+            /// Bytes become an array of numbers, matching what the
+            /// `ValueNoObj` serializer makes of them, so byte strings
+            /// survive a round trip.
+            ///
+            /// ```
+            /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+            /// use serde::de::value::{BytesDeserializer, Error};
+            /// use serde::{Deserialize, Serialize, Serializer};
+            ///
+            /// struct Bytes<'a>(&'a [u8]);
+            ///
+            /// impl Serialize for Bytes<'_> {
+            ///     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            ///         serializer.serialize_bytes(self.0)
+            ///     }
+            /// }
+            ///
+            /// let value = value_no_obj::to_value(Bytes(b"\x00hi\xff")).unwrap();
+            /// assert_eq!(value, value_no_obj!([0, 104, 105, 255]));
+            ///
+            /// let de = BytesDeserializer::<Error>::new(b"\x00hi\xff");
+            /// assert_eq!(ValueNoObj::deserialize(de).unwrap(), value);
+            ///
+            /// let back: Vec<u8> = value_no_obj::from_value(value).unwrap();
+            /// assert_eq!(back, b"\x00hi\xff");
+            /// ```
+            fn visit_bytes<E>(self, value: &[u8]) -> Result<ValueNoObj, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(ValueNoObj::Array(
+                    value
+                        .iter()
+                        .map(|&b| ValueNoObj::Number(b.into()))
+                        .collect(),
+                ))
+            }
+
+            #[inline]
+            fn visit_byte_buf<E>(self, value: Vec<u8>) -> Result<ValueNoObj, E>
+            where
+                E: serde::de::Error,
+            {
+                self.visit_bytes(&value)
+            }
+
             #[inline]
             fn visit_none<E>(self) -> Result<ValueNoObj, E> {
                 Ok(ValueNoObj::Null)
@@ -87,11 +157,33 @@ impl<'de> Deserialize<'de> for ValueNoObj {
             }
 
             #[inline]
+            /// Preallocates from the sequence's size hint when it has one.
+            /// The hint comes from the input, so it is capped to keep a
+            /// bogus length from reserving unbounded memory up front.
+            ///
+            /// ```
+            /// # use serde_json_extensions::ValueNoObj;
+            /// #
+            /// let big = ValueNoObj::Array((0..10_000).map(ValueNoObj::from).collect());
+            ///
+            /// // A `ValueNoObj` deserializer knows each array's length.
+            /// let copy: ValueNoObj = serde_json_extensions::value_no_obj::from_value(big.clone()).unwrap();
+            /// assert_eq!(copy, big);
+            /// assert!(copy.as_array().unwrap().capacity() >= 10_000);
+            ///
+            /// // JSON text offers no hint, and the array grows as it is read.
+            /// let text = big.to_string();
+            /// let parsed: ValueNoObj = text.parse().unwrap();
+            /// assert_eq!(parsed, big);
+            /// assert_eq!(parsed[9_999], 9_999);
+            /// ```
             fn visit_seq<V>(self, mut visitor: V) -> Result<ValueNoObj, V::Error>
             where
                 V: SeqAccess<'de>,
             {
-                let mut vec = Vec::new();
+                const MAX_PREALLOC: usize = 1024 * 1024 / mem::size_of::<ValueNoObj>();
+                let capacity = visitor.size_hint().map_or(0, |n| cmp::min(n, MAX_PREALLOC));
+                let mut vec = Vec::with_capacity(capacity);
 
                 while let Some(elem) = tri!(visitor.next_element()) {
                     vec.push(elem);
@@ -100,6 +192,29 @@ impl<'de> Deserialize<'de> for ValueNoObj {
                 Ok(ValueNoObj::Array(vec))
             }
 
+            /// Objects are rejected, except for the single-entry maps a
+            /// deserializer uses to hand over a number's original text under
+            /// `arbitrary_precision`. Keeping that text is what lets numbers
+            /// round-trip byte for byte.
+            ///
+            /// ```
+            /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+            /// #
+            /// for literal in ["1.0", "1e10", "0.30000000000000004", "[1.0,[1e10],-0.0]"] {
+            ///     let v: ValueNoObj = serde_json_extensions::from_str(literal).unwrap();
+            ///     let again: ValueNoObj = value_no_obj::from_value(v.clone()).unwrap();
+            ///     assert_eq!(again, v);
+            ///
+            ///     let out = serde_json_extensions::to_string(&again).unwrap();
+            ///     if cfg!(feature = "arbitrary_precision") {
+            ///         assert_eq!(out, literal);
+            ///         assert_eq!(value_no_obj::to_vec(&again).unwrap(), literal.as_bytes());
+            ///     } else if literal == "1e10" {
+            ///         // Without the feature only the parsed `f64` is kept.
+            ///         assert_eq!(out, "10000000000.0");
+            ///     }
+            /// }
+            /// ```
             #[cfg(any(feature = "std", feature = "alloc"))]
             fn visit_map<V>(self, mut visitor: V) -> Result<ValueNoObj, V::Error>
             where
@@ -116,10 +231,7 @@ impl<'de> Deserialize<'de> for ValueNoObj {
                         let value = tri!(visitor.next_value_seed(crate::raw::BoxedFromString));
                         crate::from_str(value.get()).map_err(de::Error::custom)
                     }
-                    None => Err(serde::de::Error::invalid_type(
-                        Unexpected::Map,
-                        &"`Object` isn't supported",
-                    )),
+                    Some(KeyClass::Map) | None => Err(object_not_supported()),
                 }
             }
         }
@@ -128,6 +240,35 @@ impl<'de> Deserialize<'de> for ValueNoObj {
     }
 }
 
+/// Parsing and serializing are inverses: writing a parsed value back out and
+/// parsing the result gives the same value. The `value_no_obj_roundtrip`
+/// target under `fuzz/` checks this for arbitrary input; these are some of
+/// its seeds.
+///
+/// ```
+/// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+/// #
+/// let seeds = [
+///     "0", "-0", "1e2", "1E+2", "-1e-2", "0.1", "1.5e300", "-1.7976931348623157e308",
+///     "5e-324", "2.2250738585072014e-308", "18446744073709551615", "-9223372036854775808",
+///     "18446744073709551616", "123456789012345678901234567890",
+///     r#""""#, r#""\"\\\/\b\f\n\r\t""#, r#""\u0000\u001f\u007f""#,
+///     r#""\ud83d\ude00 \u00e9 ☃""#, r#"[[], [[]], [null, true, false]]"#,
+///     r#"[-0.0, 1.0, "1.0", ["\u2028\u2029"]]"#,
+/// ];
+/// for seed in seeds {
+///     let value: ValueNoObj = seed.parse().unwrap();
+///     let compact = value_no_obj::to_vec(&value).unwrap();
+///     assert_eq!(ValueNoObj::from_slice(&compact).unwrap(), value, "{}", seed);
+///     let pretty = value_no_obj::to_vec_pretty(&value).unwrap();
+///     assert_eq!(ValueNoObj::from_slice(&pretty).unwrap(), value, "{}", seed);
+/// }
+///
+/// // Objects and malformed input are errors, never panics.
+/// for bad in ["{}", r#"[1, {"a": [2]}]"#, "[{", "{\"a\"", r#""\ud800""#, "[1,]", "\u{feff}1"] {
+///     assert!(bad.parse::<ValueNoObj>().is_err(), "{}", bad);
+/// }
+/// ```
 impl FromStr for ValueNoObj {
     type Err = Error;
     fn from_str(s: &str) -> Result<ValueNoObj, Error> {
@@ -135,6 +276,242 @@ impl FromStr for ValueNoObj {
     }
 }
 
+impl ValueNoObj {
+    /// Parses a `ValueNoObj` from bytes, such as a network buffer, without
+    /// first building a `String`.
+    ///
+    /// This is [`from_slice`](crate::from_slice) specialized to `ValueNoObj`:
+    /// the bytes must be UTF-8 wherever they form a string, and an object
+    /// anywhere in the input is an error.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// let buf: &[u8] = b"[1, [\"caf\xc3\xa9\", null]]";
+    /// assert_eq!(ValueNoObj::from_slice(buf).unwrap(), value_no_obj!([1, ["café", null]]));
+    ///
+    /// assert!(ValueNoObj::from_slice(b"[1, {}]").is_err());
+    /// assert!(ValueNoObj::from_slice(b"[1, {\"a\": 2}]").is_err());
+    /// assert!(ValueNoObj::from_slice(b"[\"\xff\"]").unwrap_err().is_syntax());
+    /// ```
+    pub fn from_slice(v: &[u8]) -> Result<ValueNoObj, Error> {
+        crate::de::from_slice(v)
+    }
+
+    /// Parses a `ValueNoObj` from an IO stream, such as a file.
+    ///
+    /// This is [`from_reader`](crate::from_reader) specialized to
+    /// `ValueNoObj`, and rejects objects in the same way. The reader is not
+    /// buffered internally; wrap it in a [`std::io::BufReader`] when reading
+    /// from a file or socket.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// use std::io::Cursor;
+    ///
+    /// let v = ValueNoObj::from_reader(Cursor::new(b"[true, [2.5]]\n")).unwrap();
+    /// assert_eq!(v, value_no_obj!([true, [2.5]]));
+    ///
+    /// assert!(ValueNoObj::from_reader(Cursor::new("{\"a\": 1}")).is_err());
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn from_reader<R>(rdr: R) -> Result<ValueNoObj, Error>
+    where
+        R: crate::io::Read,
+    {
+        crate::de::from_reader(rdr)
+    }
+
+    /// Parses a `ValueNoObj` with a custom limit on array nesting.
+    ///
+    /// Parsing fails with a "recursion limit exceeded" syntax error as soon as
+    /// the input opens its `max_depth`-th nested array, so hostile input is
+    /// rejected before it can overflow the stack. [`FromStr`] and
+    /// [`from_str`](crate::from_str) use a `max_depth` of 128, matching
+    /// `serde_json`. A `max_depth` of 0 or 1 still parses scalars but rejects
+    /// every array.
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObj;
+    /// #
+    /// assert!(ValueNoObj::from_str_with_depth("[[1]]", 3).is_ok());
+    ///
+    /// for max_depth in [0, 1] {
+    ///     assert!(ValueNoObj::from_str_with_depth("1", max_depth).is_ok());
+    ///     let err = ValueNoObj::from_str_with_depth("[1]", max_depth).unwrap_err();
+    ///     assert_eq!(err.to_string(), "recursion limit exceeded at line 1 column 1");
+    /// }
+    /// assert!(ValueNoObj::from_str_with_depth("[1]", 2).is_ok());
+    ///
+    /// let err = ValueNoObj::from_str_with_depth("[[[1]]]", 3).unwrap_err();
+    /// assert!(err.is_syntax());
+    /// assert_eq!(err.to_string(), "recursion limit exceeded at line 1 column 3");
+    ///
+    /// let deep = "[".repeat(100_000) + &"]".repeat(100_000);
+    /// assert!(ValueNoObj::from_str_with_depth(&deep, 128).is_err());
+    /// assert!(deep.parse::<ValueNoObj>().is_err());
+    /// ```
+    pub fn from_str_with_depth(s: &str, max_depth: u8) -> Result<ValueNoObj, Error> {
+        let options = ParseOptions {
+            max_depth,
+            ..ParseOptions::default()
+        };
+        ValueNoObj::parse_with(s, &options)
+    }
+
+    /// Parses a `ValueNoObj`, accepting the non-standard tokens `NaN`,
+    /// `Infinity` and `-Infinity` and mapping each of them to `Null`.
+    ///
+    /// Strict JSON has no way to spell non-finite numbers, and [`FromStr`]
+    /// rejects these tokens as a syntax error. Some producers (JSON5,
+    /// JavaScript's `String(x)`, Python's `json` module) emit them anyway;
+    /// this reads such output the same way `serde_json` serializes a
+    /// non-finite float, as `null`.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// let v = ValueNoObj::from_str_non_finite_as_null("[1, NaN, -Infinity, Infinity]").unwrap();
+    /// assert_eq!(v, value_no_obj!([1, null, null, null]));
+    ///
+    /// let err = "[1, NaN]".parse::<ValueNoObj>().unwrap_err();
+    /// assert!(err.is_syntax());
+    ///
+    /// // Only the exact tokens are recognized.
+    /// assert!(ValueNoObj::from_str_non_finite_as_null("nan").is_err());
+    /// assert!(ValueNoObj::from_str_non_finite_as_null("-Inf").is_err());
+    /// ```
+    pub fn from_str_non_finite_as_null(s: &str) -> Result<ValueNoObj, Error> {
+        let options = ParseOptions {
+            accept_nan: true,
+            on_nonfinite: NonFinite::Null,
+            ..ParseOptions::default()
+        };
+        ValueNoObj::parse_with(s, &options)
+    }
+}
+
+/// Checks that `s` is a single JSON value with no object anywhere in it,
+/// without building a `ValueNoObj`.
+///
+/// The text goes through the same tokenizer as [`from_str`](crate::from_str),
+/// so it succeeds exactly when parsing `s` as a `ValueNoObj` would, but
+/// strings and numbers are skipped over rather than allocated. Syntax errors,
+/// the nesting limit and trailing input are all reported as usual.
+///
+/// ```
+/// # use serde_json_extensions::ValueNoObj;
+/// use serde_json_extensions::value_no_obj::validate_no_objects;
+///
+/// assert!(validate_no_objects(r#"[1, ["a", [null, 2.5]], true]"#).is_ok());
+/// assert!(validate_no_objects(r#""top""#).is_ok());
+/// assert!(validate_no_objects("[]").is_ok());
+///
+/// let err = validate_no_objects(r#"[1, [2, {"a": 3}]]"#).unwrap_err();
+/// assert!(err.is_data());
+/// assert_eq!(err.to_string(), "objects are not supported at line 1 column 12");
+/// assert!(validate_no_objects("{}").is_err());
+///
+/// // The same error parsing would give.
+/// let parse_err = r#"[1, [2, {"a": 3}]]"#.parse::<ValueNoObj>().unwrap_err();
+/// assert_eq!(err.to_string(), parse_err.to_string());
+///
+/// // A string that looks like an object is fine.
+/// assert!(validate_no_objects(r#"["{}"]"#).is_ok());
+///
+/// assert!(validate_no_objects("[1,]").unwrap_err().is_syntax());
+/// assert!(validate_no_objects("[1] 2").unwrap_err().is_syntax());
+/// ```
+pub fn validate_no_objects(s: &str) -> Result<(), Error> {
+    let mut de = crate::de::Deserializer::from_str(s);
+    tri!(NoObjects.deserialize(&mut de));
+    de.end()
+}
+
+/// Accepts any value except an object, keeping nothing, in the manner of
+/// `IgnoredAny`.
+#[derive(Clone, Copy)]
+struct NoObjects;
+
+impl<'de> DeserializeSeed<'de> for NoObjects {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<(), D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for NoObjects {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("any JSON value other than an object")
+    }
+
+    fn visit_bool<E>(self, _: bool) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_i64<E>(self, _: i64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_u64<E>(self, _: u64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_f64<E>(self, _: f64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_str<E>(self, _: &str) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_none<E>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<(), D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_unit<E>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_seq<V>(self, mut visitor: V) -> Result<(), V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        while tri!(visitor.next_element_seed(self)).is_some() {}
+        Ok(())
+    }
+
+    fn visit_map<V>(self, mut visitor: V) -> Result<(), V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        match tri!(visitor.next_key_seed(KeyClassifier)) {
+            #[cfg(feature = "arbitrary_precision")]
+            Some(KeyClass::Number) => visitor.next_value::<de::IgnoredAny>().map(drop),
+            #[cfg(feature = "raw_value")]
+            Some(KeyClass::RawValueNoObj) => {
+                let value = tri!(visitor.next_value_seed(crate::raw::BoxedFromString));
+                validate_no_objects(value.get()).map_err(de::Error::custom)
+            }
+            Some(KeyClass::Map) | None => Err(object_not_supported()),
+        }
+    }
+}
+
 macro_rules! deserialize_number {
     ($method:ident) => {
         #[cfg(not(feature = "arbitrary_precision"))]
@@ -179,6 +556,38 @@ where
     }
 }
 
+fn invalid_tuple_length(len: usize, expected: usize) -> Error {
+    struct TupleLength(usize);
+
+    impl Expected for TupleLength {
+        fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a tuple of size {}", self.0)
+        }
+    }
+
+    serde::de::Error::invalid_length(len, &TupleLength(expected))
+}
+
+/// The error for a struct requested from anything but an array. Arrays
+/// deserialize positionally, one element per field, since objects are not
+/// representable.
+#[cold]
+fn invalid_struct(value: &ValueNoObj, name: &'static str) -> Error {
+    struct StructAsArray(&'static str);
+
+    impl Expected for StructAsArray {
+        fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(
+                formatter,
+                "struct {} as an array of field values (objects are not supported)",
+                self.0
+            )
+        }
+    }
+
+    value.invalid_type(&StructAsArray(name))
+}
+
 impl<'de> serde::Deserializer<'de> for ValueNoObj {
     type Error = Error;
 
@@ -224,6 +633,44 @@ impl<'de> serde::Deserializer<'de> for ValueNoObj {
     }
 
     #[inline]
+    /// Only unit variants can be represented, since the externally tagged
+    /// form of every other variant is an object. Each one is read from a
+    /// string naming it.
+    ///
+    /// ```
+    /// # use serde_json_extensions::value_no_obj::{from_value, ValueNoObj};
+    /// # use serde::Deserialize;
+    /// #
+    /// #[derive(Deserialize, Debug, PartialEq)]
+    /// enum Level {
+    ///     Low,
+    ///     High,
+    /// }
+    ///
+    /// let value = ValueNoObj::from(vec![
+    ///     ValueNoObj::from(vec!["Low"]),
+    ///     ValueNoObj::from(vec!["High", "Low"]),
+    /// ]);
+    /// let levels: Vec<Vec<Level>> = from_value(value.clone()).unwrap();
+    /// assert_eq!(levels, [vec![Level::Low], vec![Level::High, Level::Low]]);
+    ///
+    /// // Borrowing the value goes through the same path.
+    /// let levels = Vec::<Vec<Level>>::deserialize(&value).unwrap();
+    /// assert_eq!(levels[1], [Level::High, Level::Low]);
+    ///
+    /// let value = ValueNoObj::from(vec![ValueNoObj::from("Low"), ValueNoObj::from(3)]);
+    /// let err = from_value::<Vec<Level>>(value).unwrap_err();
+    /// assert!(err.is_data());
+    /// if cfg!(feature = "arbitrary_precision") {
+    ///     assert_eq!(err.to_string(), "invalid type: number, expected string");
+    /// } else {
+    ///     assert_eq!(err.to_string(), "invalid type: integer `3`, expected string");
+    /// }
+    ///
+    /// let value = ValueNoObj::from(vec!["Medium"]);
+    /// let err = from_value::<Vec<Level>>(value).unwrap_err();
+    /// assert_eq!(err.to_string(), "unknown variant `Medium`, expected `Low` or `High`");
+    /// ```
     fn deserialize_enum<V>(
         self,
         _name: &str,
@@ -238,7 +685,7 @@ impl<'de> serde::Deserializer<'de> for ValueNoObj {
             other => {
                 return Err(serde::de::Error::invalid_type(
                     other.unexpected(),
-                    &"string or map",
+                    &"string",
                 ));
             }
         };
@@ -339,6 +786,29 @@ impl<'de> serde::Deserializer<'de> for ValueNoObj {
         self.deserialize_unit(visitor)
     }
 
+    /// Sequences are read from the `Array` variant, element by element, and
+    /// anything else is an invalid type. Both an owned `ValueNoObj` and a
+    /// reference to one can be deserialized from.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// use serde::Deserialize;
+    /// use serde_json_extensions::value_no_obj::from_value;
+    ///
+    /// let value = value_no_obj!([1, -2, 3]);
+    /// let borrowed = Vec::<i32>::deserialize(&value).unwrap();
+    /// assert_eq!(borrowed, [1, -2, 3]);
+    /// let owned: Vec<i32> = from_value(value).unwrap();
+    /// assert_eq!(owned, [1, -2, 3]);
+    ///
+    /// let nested: Vec<(u8, Vec<String>)> = from_value(value_no_obj!([[1, ["a"]], [2, []]])).unwrap();
+    /// assert_eq!(nested, [(1, vec!["a".to_owned()]), (2, Vec::new())]);
+    ///
+    /// let err = from_value::<Vec<i32>>(ValueNoObj::from(1)).unwrap_err();
+    /// assert!(err.to_string().ends_with(", expected a sequence"));
+    /// let err = from_value::<Vec<i32>>(value_no_obj!([1, "2"])).unwrap_err();
+    /// assert_eq!(err.to_string(), "invalid type: string \"2\", expected i32");
+    /// ```
     fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Error>
     where
         V: Visitor<'de>,
@@ -349,11 +819,37 @@ impl<'de> serde::Deserializer<'de> for ValueNoObj {
         }
     }
 
-    fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> Result<V::Value, Error>
+    /// Tuples are read from arrays of exactly the tuple's length. Any other
+    /// length is reported as an invalid length, before any element is
+    /// visited.
+    ///
+    /// ```
+    /// # use serde_json_extensions::value_no_obj::{from_value, ValueNoObj};
+    /// #
+    /// let value = ValueNoObj::from(vec![
+    ///     ValueNoObj::from(7),
+    ///     ValueNoObj::from("seven"),
+    ///     ValueNoObj::from(true),
+    /// ]);
+    /// let tuple: (i32, String, bool) = from_value(value).unwrap();
+    /// assert_eq!(tuple, (7, "seven".to_owned(), true));
+    ///
+    /// let short = ValueNoObj::from(vec![1, 2]);
+    /// let err = from_value::<(i32, i32, i32)>(short).unwrap_err();
+    /// assert_eq!(err.to_string(), "invalid length 2, expected a tuple of size 3");
+    ///
+    /// let long = ValueNoObj::from(vec![1, 2, 3, 4]);
+    /// let err = from_value::<(i32, i32, i32)>(long).unwrap_err();
+    /// assert_eq!(err.to_string(), "invalid length 4, expected a tuple of size 3");
+    /// ```
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, Error>
     where
         V: Visitor<'de>,
     {
-        self.deserialize_seq(visitor)
+        match self {
+            ValueNoObj::Array(v) if v.len() != len => Err(invalid_tuple_length(v.len(), len)),
+            other => other.deserialize_seq(visitor),
+        }
     }
 
     fn deserialize_tuple_struct<V>(
@@ -368,16 +864,61 @@ impl<'de> serde::Deserializer<'de> for ValueNoObj {
         self.deserialize_seq(visitor)
     }
 
-    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Error>
+    /// There is no object variant to read a map from, so this always fails
+    /// with the "objects are not supported" error.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// use serde::Deserialize;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let value = value_no_obj!([["a", 1]]);
+    /// let err = BTreeMap::<String, i32>::deserialize(&value).unwrap_err();
+    /// assert!(err.is_data());
+    /// assert_eq!(err.to_string(), "objects are not supported");
+    ///
+    /// let err = serde_json_extensions::value_no_obj::from_value::<BTreeMap<String, i32>>(value)
+    ///     .unwrap_err();
+    /// assert_eq!(err.to_string(), "objects are not supported");
+    /// ```
+    fn deserialize_map<V>(self, _visitor: V) -> Result<V::Value, Error>
     where
         V: Visitor<'de>,
     {
-        Err(self.invalid_type(&visitor))
-    }
-
+        Err(object_not_supported())
+    }
+
+    /// A struct deserializes from an array holding its fields in declaration
+    /// order. Anything else fails with an error naming the struct, since a
+    /// `ValueNoObj` cannot hold the object a struct usually maps to.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// use serde::Deserialize;
+    /// use serde_json_extensions::value_no_obj::from_value;
+    ///
+    /// #[derive(Deserialize, Debug, PartialEq)]
+    /// struct Point {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let p: Point = from_value(value_no_obj!([1, 2])).unwrap();
+    /// assert_eq!(p, Point { x: 1, y: 2 });
+    ///
+    /// let err = from_value::<Point>(value_no_obj!("1,2")).unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "invalid type: string \"1,2\", expected struct Point as an array of field values (objects are not supported)",
+    /// );
+    ///
+    /// // The same holds when deserializing from a borrowed value.
+    /// let err = Point::deserialize(&ValueNoObj::Null).unwrap_err();
+    /// assert!(err.to_string().contains("expected struct Point"));
+    /// ```
     fn deserialize_struct<V>(
         self,
-        _name: &'static str,
+        name: &'static str,
         _fields: &'static [&'static str],
         visitor: V,
     ) -> Result<V::Value, Error>
@@ -386,7 +927,7 @@ impl<'de> serde::Deserializer<'de> for ValueNoObj {
     {
         match self {
             ValueNoObj::Array(v) => visit_array(v, visitor),
-            _ => Err(self.invalid_type(&visitor)),
+            _ => Err(invalid_struct(&self, name)),
         }
     }
 
@@ -642,7 +1183,7 @@ impl<'de> serde::Deserializer<'de> for &'de ValueNoObj {
             other => {
                 return Err(serde::de::Error::invalid_type(
                     other.unexpected(),
-                    &"string or map",
+                    &"string",
                 ));
             }
         };
@@ -741,6 +1282,25 @@ impl<'de> serde::Deserializer<'de> for &'de ValueNoObj {
         self.deserialize_unit(visitor)
     }
 
+    /// Deserializing from a reference leaves the value intact, and strings
+    /// in it can be borrowed rather than copied.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// use serde::Deserialize;
+    ///
+    /// let value = value_no_obj!([[1, 2], [], [3]]);
+    /// let rows = Vec::<Vec<i32>>::deserialize(&value).unwrap();
+    /// assert_eq!(rows, [vec![1, 2], vec![], vec![3]]);
+    /// assert_eq!(value, value_no_obj!([[1, 2], [], [3]]));
+    ///
+    /// let value = value_no_obj!(["a", "bc"]);
+    /// let words = Vec::<&str>::deserialize(&value).unwrap();
+    /// assert_eq!(words, ["a", "bc"]);
+    /// assert_eq!(words[1].as_ptr(), value[1].as_str().unwrap().as_ptr());
+    ///
+    /// assert!(Vec::<i32>::deserialize(&ValueNoObj::Null).is_err());
+    /// ```
     fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Error>
     where
         V: Visitor<'de>,
@@ -751,11 +1311,14 @@ impl<'de> serde::Deserializer<'de> for &'de ValueNoObj {
         }
     }
 
-    fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> Result<V::Value, Error>
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, Error>
     where
         V: Visitor<'de>,
     {
-        self.deserialize_seq(visitor)
+        match self {
+            ValueNoObj::Array(v) if v.len() != len => Err(invalid_tuple_length(v.len(), len)),
+            other => other.deserialize_seq(visitor),
+        }
     }
 
     fn deserialize_tuple_struct<V>(
@@ -770,16 +1333,16 @@ impl<'de> serde::Deserializer<'de> for &'de ValueNoObj {
         self.deserialize_seq(visitor)
     }
 
-    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Error>
+    fn deserialize_map<V>(self, _visitor: V) -> Result<V::Value, Error>
     where
         V: Visitor<'de>,
     {
-        Err(self.invalid_type(&visitor))
+        Err(object_not_supported())
     }
 
     fn deserialize_struct<V>(
         self,
-        _name: &'static str,
+        name: &'static str,
         _fields: &'static [&'static str],
         visitor: V,
     ) -> Result<V::Value, Error>
@@ -788,7 +1351,7 @@ impl<'de> serde::Deserializer<'de> for &'de ValueNoObj {
     {
         match self {
             ValueNoObj::Array(v) => visit_array_ref(v, visitor),
-            _ => Err(self.invalid_type(&visitor)),
+            _ => Err(invalid_struct(self, name)),
         }
     }
 
@@ -931,6 +1494,7 @@ impl<'de> SeqAccess<'de> for SeqRefDeserializer<'de> {
 struct KeyClassifier;
 
 enum KeyClass {
+    Map,
     #[cfg(feature = "arbitrary_precision")]
     Number,
     #[cfg(feature = "raw_value")]
@@ -964,7 +1528,7 @@ impl<'de> Visitor<'de> for KeyClassifier {
             crate::number::TOKEN => Ok(KeyClass::Number),
             #[cfg(feature = "raw_value")]
             crate::raw::TOKEN => Ok(KeyClass::RawValueNoObj),
-            _ => unreachable!(),
+            _ => Ok(KeyClass::Map),
         }
     }
 
@@ -978,14 +1542,14 @@ impl<'de> Visitor<'de> for KeyClassifier {
             crate::number::TOKEN => Ok(KeyClass::Number),
             #[cfg(feature = "raw_value")]
             crate::raw::TOKEN => Ok(KeyClass::RawValueNoObj),
-            _ => unreachable!(),
+            _ => Ok(KeyClass::Map),
         }
     }
 }
 
 impl ValueNoObj {
     #[cold]
-    fn invalid_type<E>(&self, exp: &dyn Expected) -> E
+    pub(crate) fn invalid_type<E>(&self, exp: &dyn Expected) -> E
     where
         E: serde::de::Error,
     {
@@ -993,7 +1557,7 @@ impl ValueNoObj {
     }
 
     #[cold]
-    fn unexpected(&self) -> Unexpected {
+    pub(crate) fn unexpected(&self) -> Unexpected {
         match self {
             ValueNoObj::Null => Unexpected::Unit,
             ValueNoObj::Bool(b) => Unexpected::Bool(*b),
@@ -1002,4 +1566,15 @@ impl ValueNoObj {
             ValueNoObj::Array(_) => Unexpected::Seq,
         }
     }
+
+    #[cold]
+    pub(crate) fn index_out_of_range<E>(index: usize, len: usize) -> E
+    where
+        E: serde::de::Error,
+    {
+        serde::de::Error::custom(format_args!(
+            "index {} out of range for array of length {}",
+            index, len
+        ))
+    }
 }
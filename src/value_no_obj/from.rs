@@ -1,6 +1,6 @@
 use super::ValueNoObj;
-use crate::map::Map;
 use crate::number::Number;
+use crate::value_no_obj_or_arr::ValueNoObjOrArr;
 use alloc::borrow::Cow;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
@@ -109,6 +109,25 @@ impl From<&str> for ValueNoObj {
     }
 }
 
+impl From<char> for ValueNoObj {
+    /// Convert a `char` to a one-character `ValueNoObj::String`, the same string
+    /// that serializing the `char` produces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObj;
+    /// #
+    /// let x = ValueNoObj::from('x');
+    /// assert_eq!(x, "x");
+    /// assert_eq!(x, ValueNoObj::from("x"));
+    /// assert_eq!(ValueNoObj::from('é').as_str(), Some("é"));
+    /// ```
+    fn from(f: char) -> Self {
+        ValueNoObj::String(f.to_string())
+    }
+}
+
 impl<'a> From<Cow<'a, str>> for ValueNoObj {
     /// Convert copy-on-write string to `Value::String`.
     ///
@@ -150,20 +169,29 @@ impl From<Number> for ValueNoObj {
     }
 }
 
-impl From<Map<String, ValueNoObj>> for ValueNoObj {
-    /// Convert map (with string keys) to `Value::Object`.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use serde_json::{Map, Value};
-    ///
-    /// let mut m = Map::new();
-    /// m.insert("Lorem".to_string(), "ipsum".into());
-    /// let x: Value = m.into();
-    /// ```
-    fn from(_f: Map<String, ValueNoObj>) -> Self {
-        unimplemented!()
+impl From<ValueNoObjOrArr> for ValueNoObj {
+    /// Widens a scalar into the same scalar variant of `ValueNoObj`. This
+    /// never fails, since every `ValueNoObjOrArr` is also a `ValueNoObj`;
+    /// [`ValueNoObjOrArr::try_from`] goes the other way.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj, ValueNoObjOrArr};
+    /// #
+    /// let v = ValueNoObj::from(ValueNoObjOrArr::from("x"));
+    /// assert_eq!(v, "x");
+    /// assert_eq!(ValueNoObj::from(ValueNoObjOrArr::Null), ValueNoObj::Null);
+    ///
+    /// // Collections of scalars widen too.
+    /// let list = vec![ValueNoObjOrArr::from(1), ValueNoObjOrArr::from(true)];
+    /// assert_eq!(ValueNoObj::from(list), value_no_obj!([1, true]));
+    /// ```
+    fn from(f: ValueNoObjOrArr) -> Self {
+        match f {
+            ValueNoObjOrArr::Null => ValueNoObj::Null,
+            ValueNoObjOrArr::Bool(b) => ValueNoObj::Bool(b),
+            ValueNoObjOrArr::Number(n) => ValueNoObj::Number(n),
+            ValueNoObjOrArr::String(s) => ValueNoObj::String(s),
+        }
     }
 }
 
@@ -183,6 +211,28 @@ impl<T: Into<ValueNoObj>> From<Vec<T>> for ValueNoObj {
     }
 }
 
+impl<T: Into<ValueNoObj>, const N: usize> From<[T; N]> for ValueNoObj {
+    /// Convert an array to `ValueNoObj::Array`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// let x = ValueNoObj::from([true, false]);
+    /// assert_eq!(x, value_no_obj!([true, false]));
+    ///
+    /// let nested = ValueNoObj::from([[1, 2], [3, 4]]);
+    /// assert_eq!(nested, value_no_obj!([[1, 2], [3, 4]]));
+    ///
+    /// let empty: [bool; 0] = [];
+    /// assert_eq!(ValueNoObj::from(empty), value_no_obj!([]));
+    /// ```
+    fn from(f: [T; N]) -> Self {
+        ValueNoObj::Array(f.into_iter().map(Into::into).collect())
+    }
+}
+
 impl<T: Clone + Into<ValueNoObj>> From<&[T]> for ValueNoObj {
     /// Convert a slice to `Value::Array`.
     ///
@@ -199,49 +249,81 @@ impl<T: Clone + Into<ValueNoObj>> From<&[T]> for ValueNoObj {
     }
 }
 
+/// Collects an iterator of array elements into a `ValueNoObj::Array`.
+///
+/// ```
+/// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+/// #
+/// let v: ValueNoObj = std::iter::repeat(42).take(3).collect();
+/// assert_eq!(v, value_no_obj!([42, 42, 42]));
+///
+/// let v = ValueNoObj::from_iter(vec!["lorem", "ipsum"]);
+/// assert_eq!(v, value_no_obj!(["lorem", "ipsum"]));
+///
+/// let rows: ValueNoObj = (1..3).map(|n| value_no_obj!([n, n * n])).collect();
+/// assert_eq!(rows, value_no_obj!([[1, 1], [2, 4]]));
+/// ```
+///
+/// There is deliberately no `FromIterator<(K, V)>`, since key-value pairs
+/// would make an object. Collecting pairs fails to compile, because a tuple
+/// does not implement `Into<ValueNoObj>`, rather than panicking at runtime.
+/// The UI tests under `tests/ui` check that the error names that bound.
+///
+/// ```compile_fail,E0277
+/// # use serde_json_extensions::ValueNoObj;
+/// let v: ValueNoObj = vec![("a", 1), ("b", 2)].into_iter().collect();
+/// ```
+///
+/// ```compile_fail,E0277
+/// # use std::collections::BTreeMap;
+/// # use serde_json_extensions::ValueNoObj;
+/// let map = BTreeMap::from([("a", 1)]);
+/// let v: ValueNoObj = map.into_iter().collect();
+/// ```
+///
+/// To keep the pairs, turn each one into a two-element array first.
+///
+/// ```
+/// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+/// #
+/// let pairs = vec![("a", 1), ("b", 2)];
+/// let v: ValueNoObj = pairs.into_iter().map(|(k, v)| value_no_obj!([k, v])).collect();
+/// assert_eq!(v, value_no_obj!([["a", 1], ["b", 2]]));
+/// ```
 impl<T: Into<ValueNoObj>> FromIterator<T> for ValueNoObj {
-    /// Create a `Value::Array` by collecting an iterator of array elements.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use serde_json::Value;
-    ///
-    /// let v = std::iter::repeat(42).take(5);
-    /// let x: Value = v.collect();
-    /// ```
-    ///
-    /// ```
-    /// use serde_json::Value;
-    ///
-    /// let v: Vec<_> = vec!["lorem", "ipsum", "dolor"];
-    /// let x: Value = v.into_iter().collect();
-    /// ```
-    ///
-    /// ```
-    /// use std::iter::FromIterator;
-    /// use serde_json::Value;
-    ///
-    /// let x: Value = Value::from_iter(vec!["lorem", "ipsum", "dolor"]);
-    /// ```
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         ValueNoObj::Array(iter.into_iter().map(Into::into).collect())
     }
 }
 
-impl<K: Into<String>, V: Into<ValueNoObj>> FromIterator<(K, V)> for ValueNoObj {
-    /// Create a `Value::Object` by collecting an iterator of key-value pairs.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use serde_json::Value;
-    ///
-    /// let v: Vec<_> = vec![("lorem", 40), ("ipsum", 2)];
-    /// let x: Value = v.into_iter().collect();
-    /// ```
-    fn from_iter<I: IntoIterator<Item = (K, V)>>(_iter: I) -> Self {
-        unimplemented!()
+/// Appends to a `ValueNoObj::Array`.
+///
+/// As with [`ValueNoObj::push`], a value that is not an array is turned into
+/// one first: `Null` becomes an empty array, even if the iterator is empty,
+/// and any other scalar becomes the first element.
+///
+/// ```
+/// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+/// #
+/// let mut v = value_no_obj!([1, 2]);
+/// v.extend(3..5);
+/// assert_eq!(v, value_no_obj!([1, 2, 3, 4]));
+///
+/// let mut null = ValueNoObj::Null;
+/// null.extend(vec!["a", "b"]);
+/// assert_eq!(null, value_no_obj!(["a", "b"]));
+///
+/// let mut empty = ValueNoObj::Null;
+/// empty.extend(Vec::<bool>::new());
+/// assert_eq!(empty, value_no_obj!([]));
+///
+/// let mut scalar = ValueNoObj::from("x");
+/// scalar.extend(Some(value_no_obj!([null])));
+/// assert_eq!(scalar, value_no_obj!(["x", [null]]));
+/// ```
+impl<T: Into<ValueNoObj>> Extend<T> for ValueNoObj {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.make_array().extend(iter.into_iter().map(Into::into));
     }
 }
 
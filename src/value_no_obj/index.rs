@@ -85,8 +85,8 @@ impl Index for str {
     fn index_into_mut<'v>(&self, _v: &'v mut ValueNoObj) -> Option<&'v mut ValueNoObj> {
         None
     }
-    fn index_or_insert<'v>(&self, _v: &'v mut ValueNoObj) -> &'v mut ValueNoObj {
-        unimplemented!()
+    fn index_or_insert<'v>(&self, v: &'v mut ValueNoObj) -> &'v mut ValueNoObj {
+        panic!("cannot access key {:?} in JSON {}", self, Type(v))
     }
 }
 
@@ -166,33 +166,37 @@ where
 {
     type Output = ValueNoObj;
 
-    /// Index into a `serde_json::Value` using the syntax `value[0]` or
-    /// `value["k"]`.
+    /// Index into a `ValueNoObj` using the syntax `value[0]`.
+    ///
+    /// Returns `ValueNoObj::Null` if the type of `self` does not match the
+    /// type of the index, for example if `self` is a number or a string. Also
+    /// returns `ValueNoObj::Null` if the given index is not within the bounds
+    /// of the array. Indexing with a string always returns `ValueNoObj::Null`
+    /// because a `ValueNoObj` never holds an object.
     ///
-    /// Returns `Value::Null` if the type of `self` does not match the type of
-    /// the index, for example if the index is a string and `self` is an array
-    /// or a number. Also returns `Value::Null` if the given key does not exist
-    /// in the map or the given index is not within the bounds of the array.
+    /// Because of this, `value[i]` cannot tell apart a missing element from an
+    /// element that is present and null. Use [`ValueNoObj::get`] when that
+    /// distinction matters.
     ///
     /// For retrieving deeply nested values, you should have a look at the
-    /// `Value::pointer` method.
+    /// `ValueNoObj::pointer` method.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use serde_json::json;
+    /// # use serde_json_extensions::ValueNoObj;
     /// #
-    /// let data = json!({
-    ///     "x": {
-    ///         "y": ["z", "zz"]
-    ///     }
-    /// });
+    /// let data = ValueNoObj::from(vec![
+    ///     ValueNoObj::from("z"),
+    ///     ValueNoObj::from(vec!["y", "yy"]),
+    /// ]);
     ///
-    /// assert_eq!(data["x"]["y"], json!(["z", "zz"]));
-    /// assert_eq!(data["x"]["y"][0], json!("z"));
+    /// assert_eq!(data[0], "z");
+    /// assert_eq!(data[1][1], "yy");
     ///
-    /// assert_eq!(data["a"], json!(null)); // returns null for undefined values
-    /// assert_eq!(data["a"]["b"], json!(null)); // does not panic
+    /// assert_eq!(data[2], ValueNoObj::Null); // returns null for out of range
+    /// assert_eq!(data[0][0], ValueNoObj::Null); // returns null for non-arrays
+    /// assert_eq!(data["a"], ValueNoObj::Null); // there are no objects
     /// ```
     fn index(&self, index: I) -> &ValueNoObj {
         static NULL: ValueNoObj = ValueNoObj::Null;
@@ -204,38 +208,33 @@ impl<I> ops::IndexMut<I> for ValueNoObj
 where
     I: Index,
 {
-    /// Write into a `serde_json::Value` using the syntax `value[0] = ...` or
-    /// `value["k"] = ...`.
+    /// Write into a `ValueNoObj` using the syntax `value[0] = ...`.
     ///
-    /// If the index is a number, the value must be an array of length bigger
-    /// than the index. Indexing into a value that is not an array or an array
-    /// that is too small will panic.
-    ///
-    /// If the index is a string, the value must be an object or null which is
-    /// treated like an empty object. If the key is not already present in the
-    /// object, it will be inserted with a value of null. Indexing into a value
-    /// that is neither an object nor null will panic.
+    /// The value must be an array of length bigger than the index. Like
+    /// `serde_json::Value`, the array is not grown to fit: indexing into a
+    /// value that is not an array, or into an array that is too small, will
+    /// panic. Indexing with a string always panics because a `ValueNoObj`
+    /// never holds an object.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use serde_json::json;
+    /// # use serde_json_extensions::ValueNoObj;
     /// #
-    /// let mut data = json!({ "x": 0 });
-    ///
-    /// // replace an existing key
-    /// data["x"] = json!(1);
-    ///
-    /// // insert a new key
-    /// data["y"] = json!([false, false, false]);
+    /// let mut data = ValueNoObj::from(vec![false, false, false]);
     ///
     /// // replace an array value
-    /// data["y"][0] = json!(true);
+    /// data[0] = ValueNoObj::Bool(true);
+    /// assert_eq!(data, ValueNoObj::from(vec![true, false, false]));
+    /// ```
     ///
-    /// // inserted a deeply nested key
-    /// data["a"]["b"]["c"]["d"] = json!(true);
+    /// ```should_panic
+    /// # use serde_json_extensions::ValueNoObj;
+    /// #
+    /// let mut data = ValueNoObj::from(vec![false]);
     ///
-    /// println!("{}", data);
+    /// // cannot access index 1 of JSON array of length 1
+    /// data[1] = ValueNoObj::Bool(true);
     /// ```
     fn index_mut(&mut self, index: I) -> &mut ValueNoObj {
         index.index_or_insert(self)
@@ -0,0 +1,159 @@
+use super::ValueNoObj;
+use alloc::vec::{self, Vec};
+use core::iter::FusedIterator;
+use core::slice;
+
+macro_rules! delegate_iterator {
+    (($name:ident $($generics:tt)*) => $item:ty) => {
+        impl $($generics)* Iterator for $name $($generics)* {
+            type Item = $item;
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.iter.next()
+            }
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.iter.size_hint()
+            }
+        }
+
+        impl $($generics)* DoubleEndedIterator for $name $($generics)* {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.iter.next_back()
+            }
+        }
+
+        impl $($generics)* ExactSizeIterator for $name $($generics)* {
+            #[inline]
+            fn len(&self) -> usize {
+                self.iter.len()
+            }
+        }
+
+        impl $($generics)* FusedIterator for $name $($generics)* {}
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+
+/// An iterator over the elements of a `ValueNoObj::Array`.
+///
+/// This struct is created by the [`iter`] method on [`ValueNoObj`]. Any
+/// variant other than `Array` produces an empty iterator.
+///
+/// [`iter`]: ValueNoObj::iter
+pub struct Iter<'a> {
+    iter: slice::Iter<'a, ValueNoObj>,
+}
+
+impl<'a> Iter<'a> {
+    pub(super) fn new(value: &'a ValueNoObj) -> Self {
+        let slice: &[ValueNoObj] = match value {
+            ValueNoObj::Array(list) => list,
+            _ => &[],
+        };
+        Iter { iter: slice.iter() }
+    }
+}
+
+delegate_iterator!((Iter<'a>) => &'a ValueNoObj);
+
+impl<'a> IntoIterator for &'a ValueNoObj {
+    type Item = &'a ValueNoObj;
+    type IntoIter = Iter<'a>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        Iter::new(self)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+
+/// A mutable iterator over the elements of a `ValueNoObj::Array`.
+///
+/// This struct is created by the [`iter_mut`] method on [`ValueNoObj`]. Any
+/// variant other than `Array` produces an empty iterator.
+///
+/// [`iter_mut`]: ValueNoObj::iter_mut
+pub struct IterMut<'a> {
+    iter: slice::IterMut<'a, ValueNoObj>,
+}
+
+impl<'a> IterMut<'a> {
+    pub(super) fn new(value: &'a mut ValueNoObj) -> Self {
+        let slice: &mut [ValueNoObj] = match value {
+            ValueNoObj::Array(list) => list,
+            _ => &mut [],
+        };
+        IterMut {
+            iter: slice.iter_mut(),
+        }
+    }
+}
+
+delegate_iterator!((IterMut<'a>) => &'a mut ValueNoObj);
+
+impl<'a> IntoIterator for &'a mut ValueNoObj {
+    type Item = &'a mut ValueNoObj;
+    type IntoIter = IterMut<'a>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut::new(self)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+
+/// An owning iterator over the elements of a `ValueNoObj::Array`.
+///
+/// This struct is created by the `into_iter` method on [`ValueNoObj`]
+/// (provided by the [`IntoIterator`] trait). Any variant other than `Array`
+/// produces an empty iterator.
+pub struct IntoIter {
+    iter: vec::IntoIter<ValueNoObj>,
+}
+
+delegate_iterator!((IntoIter) => ValueNoObj);
+
+/// Moves the elements out of an array, so they can be transformed without
+/// cloning. Any other variant yields nothing.
+///
+/// ```
+/// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+/// use serde_json_extensions::value_no_obj::IntoIter;
+///
+/// let v = value_no_obj!(["a", [1, 2], null]);
+/// let iter: IntoIter = v.into_iter();
+/// assert_eq!(iter.len(), 3);
+/// let owned: Vec<ValueNoObj> = iter.collect();
+/// assert_eq!(owned, [value_no_obj!("a"), value_no_obj!([1, 2]), ValueNoObj::Null]);
+///
+/// // Strings are moved, not copied.
+/// let v = value_no_obj!(["moved"]);
+/// let ptr = v[0].as_str().unwrap().as_ptr();
+/// let mut strings: Vec<String> = v
+///     .into_iter()
+///     .filter_map(|e| match e {
+///         ValueNoObj::String(s) => Some(s),
+///         _ => None,
+///     })
+///     .collect();
+/// assert_eq!(strings.pop().unwrap().as_ptr(), ptr);
+///
+/// assert_eq!(ValueNoObj::from(1).into_iter().count(), 0);
+/// ```
+impl IntoIterator for ValueNoObj {
+    type Item = ValueNoObj;
+    type IntoIter = IntoIter;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        let list = match self {
+            ValueNoObj::Array(list) => list,
+            _ => Vec::new(),
+        };
+        IntoIter {
+            iter: list.into_iter(),
+        }
+    }
+}
@@ -90,6 +90,7 @@
 //! [from_slice]: crate::de::from_slice
 //! [from_reader]: crate::de::from_reader
 
+use alloc::collections::BTreeMap;
 use alloc::string::String;
 use alloc::vec::Vec;
 use core::fmt::{self, Debug, Display};
@@ -98,8 +99,19 @@ use core::str;
 use serde::de::DeserializeOwned;
 use serde::ser::Serialize;
 
+pub use self::de::validate_no_objects;
 pub use self::index::Index;
+pub use self::iter::{IntoIter, Iter, IterMut};
+pub use self::parse::{NonFinite, ParseOptions};
+pub use self::patch::PatchOp;
+#[cfg(feature = "raw_value")]
+pub use self::raw_numbers::WithRawNumbers;
 pub use self::ser::Serializer;
+pub use self::shared::ValueNoObjShared;
+pub use self::tokens::{JsonToken, Tokens};
+#[cfg(feature = "std")]
+pub use self::write::to_writer_with;
+pub use self::write::{to_string_with, to_vec_with, WriteOptions};
 
 #[path = "map.rs"]
 pub(crate) mod map;
@@ -108,6 +120,7 @@ pub use map::Map;
 use crate::error::Error;
 use crate::io;
 pub use crate::number::Number;
+use crate::ser::{CompactFormatter, PrettyFormatter};
 
 #[cfg(feature = "raw_value")]
 #[cfg_attr(docsrs, doc(cfg(feature = "raw_value")))]
@@ -116,6 +129,79 @@ pub use crate::raw::{to_raw_value, RawValue};
 /// Represents any valid JSON value *except* objects.
 ///
 /// See the [`serde_json::value` module documentation](self) for usage examples.
+///
+/// # Equality
+///
+/// Equality is structural: arrays are equal when they have the same length
+/// and their elements are pairwise equal, in order, at every depth. Numbers
+/// compare by representation as well as value, so the integer `1` and the
+/// float `1.0` are *not* equal. With the `arbitrary_precision` feature,
+/// numbers compare by their exact text, so `1.0` and `1.00` differ too.
+///
+/// ```
+/// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+/// #
+/// let nested = value_no_obj!([1, ["a", [true, [null, 2.5]]]]);
+/// assert_eq!(nested, value_no_obj!([1, ["a", [true, [null, 2.5]]]]));
+///
+/// // A difference at the innermost level is detected.
+/// assert_ne!(nested, value_no_obj!([1, ["a", [true, [null, 2.6]]]]));
+///
+/// // Order and length matter.
+/// assert_ne!(value_no_obj!([1, 2]), value_no_obj!([2, 1]));
+/// assert_ne!(value_no_obj!([[1]]), value_no_obj!([[1, 1]]));
+///
+/// // Integers and floats are distinct, even when nested.
+/// assert_ne!(ValueNoObj::from(1), ValueNoObj::from(1.0));
+/// assert_ne!(value_no_obj!([[1]]), value_no_obj!([[1.0]]));
+/// ```
+///
+/// # No object conversions
+///
+/// `ValueNoObj` implements neither `From<Map<String, _>>` nor
+/// `FromIterator<(K, V)>`, so attempting to build one from key-value pairs
+/// is a compile error rather than a runtime panic. The UI tests under
+/// `tests/ui` check the exact errors.
+///
+/// ```compile_fail,E0277
+/// # use serde_json_extensions::value_no_obj::{Map, ValueNoObj};
+/// let v = ValueNoObj::from(Map::<String, ValueNoObj>::new());
+/// ```
+///
+/// ```compile_fail,E0277
+/// # use serde_json_extensions::ValueNoObj;
+/// let v: ValueNoObj = vec![("k", 1)].into_iter().collect();
+/// ```
+///
+/// # Hashing
+///
+/// `Hash` agrees with this equality. The derived impl hashes the variant and
+/// then its contents, recursing through nested arrays, so parsed values can
+/// key a `HashMap`, e.g. to memoize work over arrays.
+///
+/// ```
+/// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::collections::HashMap;
+/// use std::hash::{Hash, Hasher};
+///
+/// fn hash(value: &ValueNoObj) -> u64 {
+///     let mut hasher = DefaultHasher::new();
+///     value.hash(&mut hasher);
+///     hasher.finish()
+/// }
+///
+/// let a: ValueNoObj = "[1, [2.5, \"x\"], null]".parse().unwrap();
+/// let b = value_no_obj!([1, [2.5, "x"], null]);
+/// assert_eq!(hash(&a), hash(&b));
+///
+/// assert_ne!(hash(&a), hash(&value_no_obj!([1, [2.5, "y"], null])));
+/// assert_ne!(hash(&value_no_obj!([[1], 2])), hash(&value_no_obj!([1, [2]])));
+///
+/// let mut memo = HashMap::new();
+/// memo.insert(a, "cached");
+/// assert_eq!(memo.get(&b), Some(&"cached"));
+/// ```
 #[derive(Clone, Eq, PartialEq, Hash)]
 pub enum ValueNoObj {
     /// Represents a JSON null value.
@@ -176,64 +262,115 @@ impl Display for ValueNoObj {
     /// Display a JSON value as a string.
     ///
     /// ```
-    /// # use serde_json::json;
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
     /// #
-    /// let json = json!({ "city": "London", "street": "10 Downing Street" });
+    /// let value = value_no_obj!(["London", [10, "Downing Street"]]);
     ///
     /// // Compact format:
     /// //
-    /// // {"city":"London","street":"10 Downing Street"}
-    /// let compact = format!("{}", json);
-    /// assert_eq!(compact,
-    ///     "{\"city\":\"London\",\"street\":\"10 Downing Street\"}");
+    /// // ["London",[10,"Downing Street"]]
+    /// let compact = format!("{}", value);
+    /// assert_eq!(compact, r#"["London",[10,"Downing Street"]]"#);
     ///
     /// // Pretty format:
     /// //
-    /// // {
-    /// //   "city": "London",
-    /// //   "street": "10 Downing Street"
-    /// // }
-    /// let pretty = format!("{:#}", json);
+    /// // [
+    /// //   "London",
+    /// //   [
+    /// //     10,
+    /// //     "Downing Street"
+    /// //   ]
+    /// // ]
+    /// let pretty = format!("{:#}", value);
     /// assert_eq!(pretty,
-    ///     "{\n  \"city\": \"London\",\n  \"street\": \"10 Downing Street\"\n}");
+    ///     "[\n  \"London\",\n  [\n    10,\n    \"Downing Street\"\n  ]\n]");
+    /// ```
+    ///
+    /// Formatting does not recurse, so even adversarially deep arrays can be
+    /// displayed. (The generic [`Serialize`] impl, by contrast, recurses once
+    /// per level, as every serde serializer does.)
+    ///
+    /// Dropping a `ValueNoObj` still recurses once per level, as dropping
+    /// nested `Vec`s does, so a value tens of thousands of levels deep can
+    /// overflow the stack when it goes out of scope. Take such a value apart
+    /// with an explicit stack instead, as the end of this example does.
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObj;
+    /// #
+    /// let mut value = ValueNoObj::Array(Vec::new());
+    /// for _ in 0..10_000 {
+    ///     value = ValueNoObj::Array(vec![value]);
+    /// }
+    ///
+    /// let text = value.to_string();
+    /// assert_eq!(text.len(), 2 * 10_001);
+    /// assert!(text.starts_with("[[[") && text.ends_with("]]]"));
+    ///
+    /// // Move each level's elements out before dropping it, so no drop
+    /// // recurses.
+    /// let mut stack = vec![value];
+    /// while let Some(value) = stack.pop() {
+    ///     if let ValueNoObj::Array(list) = value {
+    ///         stack.extend(list);
+    ///     }
+    /// }
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        struct WriterFormatter<'a, 'b: 'a> {
-            inner: &'a mut fmt::Formatter<'b>,
+        if f.alternate() {
+            // {:#}
+            self.write_json(&mut FmtWriter { inner: f }, &mut PrettyFormatter::new())
+                .map_err(|_| fmt::Error)
+        } else {
+            // {}
+            self.write_compact(f)
         }
+    }
+}
 
-        impl<'a, 'b> io::Write for WriterFormatter<'a, 'b> {
-            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-                // Safety: the serializer below only emits valid utf8 when using
-                // the default formatter.
-                let s = unsafe { str::from_utf8_unchecked(buf) };
-                tri!(self.inner.write_str(s).map_err(io_error));
-                Ok(buf.len())
-            }
+/// Adapts a `fmt::Write` sink to the `io::Write` that `write_json` emits
+/// into.
+struct FmtWriter<'a, W: ?Sized> {
+    inner: &'a mut W,
+}
 
-            fn flush(&mut self) -> io::Result<()> {
-                Ok(())
-            }
-        }
+impl<'a, W> io::Write for FmtWriter<'a, W>
+where
+    W: ?Sized + fmt::Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        tri!(self.write_all(buf));
+        Ok(buf.len())
+    }
 
-        fn io_error(_: fmt::Error) -> io::Error {
-            // Error value does not matter because Display impl just maps it
-            // back to fmt::Error.
-            io::Error::new(io::ErrorKind::Other, "fmt error")
-        }
+    // Overridden because the no_std `io::Write::write_all` assumes every
+    // write succeeds and would drop the sink's error.
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        // Safety: `write_json` only emits valid utf8 with the compact and
+        // pretty formatters it is given here.
+        let s = unsafe { str::from_utf8_unchecked(buf) };
+        self.inner.write_str(s).map_err(io_error)
+    }
 
-        let alternate = f.alternate();
-        let mut wr = WriterFormatter { inner: f };
-        if alternate {
-            // {:#}
-            super::ser::to_writer_pretty(&mut wr, self).map_err(|_| fmt::Error)
-        } else {
-            // {}
-            super::ser::to_writer(&mut wr, self).map_err(|_| fmt::Error)
-        }
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
     }
 }
 
+fn io_error(_: fmt::Error) -> io::Error {
+    // Error value does not matter because callers just map it back to
+    // fmt::Error.
+    io::Error::new(io::ErrorKind::Other, "fmt error")
+}
+
+fn child_path(path: &str, index: usize) -> String {
+    let mut child = String::with_capacity(path.len() + 4);
+    child.push_str(path);
+    child.push('/');
+    child.push_str(itoa::Buffer::new().format(index));
+    child
+}
+
 fn parse_index(s: &str) -> Option<usize> {
     if s.starts_with('+') || (s.starts_with('0') && s.len() != 1) {
         return None;
@@ -242,65 +379,136 @@ fn parse_index(s: &str) -> Option<usize> {
 }
 
 impl ValueNoObj {
-    /// Index into a JSON array or map. A string index can be used to access a
-    /// value in a map, and a usize index can be used to access an element of an
-    /// array.
+    /// Constructs a `ValueNoObj::Null`.
     ///
-    /// Returns `None` if the type of `self` does not match the type of the
-    /// index, for example if the index is a string and `self` is an array or a
-    /// number. Also returns `None` if the given key does not exist in the map
-    /// or the given index is not within the bounds of the array.
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// assert_eq!(ValueNoObj::null(), value_no_obj!(null));
+    /// ```
+    pub fn null() -> ValueNoObj {
+        ValueNoObj::Null
+    }
+
+    /// Constructs a `ValueNoObj::Bool`.
     ///
     /// ```
-    /// # use serde_json::json;
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// assert_eq!(ValueNoObj::bool(true), value_no_obj!(true));
+    /// ```
+    pub fn bool(b: bool) -> ValueNoObj {
+        ValueNoObj::Bool(b)
+    }
+
+    /// Constructs a `ValueNoObj::String` from anything convertible to a
+    /// `String`.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// assert_eq!(ValueNoObj::string("lorem"), value_no_obj!("lorem"));
+    /// assert_eq!(ValueNoObj::string(String::from("lorem")), value_no_obj!("lorem"));
+    /// ```
+    pub fn string<S: Into<String>>(s: S) -> ValueNoObj {
+        ValueNoObj::String(s.into())
+    }
+
+    /// Constructs a `ValueNoObj::Number` from anything convertible to a
+    /// [`Number`], which covers every primitive integer type.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, Number, ValueNoObj};
+    /// #
+    /// assert_eq!(ValueNoObj::number(7u8), value_no_obj!(7));
+    /// assert_eq!(ValueNoObj::number(-7i64), value_no_obj!(-7));
+    /// assert_eq!(ValueNoObj::number(Number::from_f64(0.5).unwrap()), value_no_obj!(0.5));
+    /// ```
+    pub fn number<N: Into<Number>>(n: N) -> ValueNoObj {
+        ValueNoObj::Number(n.into())
+    }
+
+    /// Constructs a `ValueNoObj::Array` from the elements of an iterator,
+    /// converting each one into a `ValueNoObj`.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
     /// #
-    /// let object = json!({ "A": 65, "B": 66, "C": 67 });
-    /// assert_eq!(*object.get("A").unwrap(), json!(65));
+    /// assert_eq!(ValueNoObj::array([1, 2, 3]), value_no_obj!([1, 2, 3]));
+    ///
+    /// let v = ValueNoObj::array([
+    ///     ValueNoObj::string("a"),
+    ///     ValueNoObj::array([ValueNoObj::null(), ValueNoObj::bool(false)]),
+    /// ]);
+    /// assert_eq!(v, value_no_obj!(["a", [null, false]]));
+    ///
+    /// assert_eq!(ValueNoObj::array(Vec::<ValueNoObj>::new()), value_no_obj!([]));
+    /// ```
+    pub fn array<I, T>(elements: I) -> ValueNoObj
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<ValueNoObj>,
+    {
+        ValueNoObj::Array(elements.into_iter().map(Into::into).collect())
+    }
+
+    /// Index into a JSON array. A usize index can be used to access an
+    /// element of an array.
     ///
-    /// let array = json!([ "A", "B", "C" ]);
-    /// assert_eq!(*array.get(2).unwrap(), json!("C"));
+    /// Returns `None` if `self` is not an array, if the given index is not
+    /// within the bounds of the array, or if the index is a string (a
+    /// `ValueNoObj` never holds an object). Unlike square-bracket indexing,
+    /// this distinguishes a missing element from one that is present and
+    /// null.
     ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObj;
+    /// #
+    /// let array = ValueNoObj::from(vec![
+    ///     ValueNoObj::from("A"),
+    ///     ValueNoObj::Null,
+    /// ]);
+    /// assert_eq!(*array.get(0).unwrap(), "A");
+    /// assert_eq!(array.get(1), Some(&ValueNoObj::Null));
+    /// assert_eq!(array.get(2), None);
     /// assert_eq!(array.get("A"), None);
+    ///
+    /// assert_eq!(ValueNoObj::Null.get(0), None);
     /// ```
     ///
     /// Square brackets can also be used to index into a value in a more concise
-    /// way. This returns `Value::Null` in cases where `get` would have returned
-    /// `None`.
+    /// way. This returns `ValueNoObj::Null` in cases where `get` would have
+    /// returned `None`.
     ///
     /// ```
-    /// # use serde_json::json;
+    /// # use serde_json_extensions::ValueNoObj;
     /// #
-    /// let object = json!({
-    ///     "A": ["a", "á", "à"],
-    ///     "B": ["b", "b́"],
-    ///     "C": ["c", "ć", "ć̣", "ḉ"],
-    /// });
-    /// assert_eq!(object["B"][0], json!("b"));
+    /// let array = ValueNoObj::from(vec![vec!["a", "á"], vec!["b"]]);
+    /// assert_eq!(array[1][0], "b");
     ///
-    /// assert_eq!(object["D"], json!(null));
-    /// assert_eq!(object[0]["x"]["y"]["z"], json!(null));
+    /// assert_eq!(array[2], ValueNoObj::Null);
+    /// assert_eq!(array[0][5][1], ValueNoObj::Null);
     /// ```
     pub fn get<I: Index>(&self, index: I) -> Option<&ValueNoObj> {
         index.index_into(self)
     }
 
-    /// Mutably index into a JSON array or map. A string index can be used to
-    /// access a value in a map, and a usize index can be used to access an
-    /// element of an array.
+    /// Mutably index into a JSON array. A usize index can be used to access
+    /// an element of an array.
     ///
-    /// Returns `None` if the type of `self` does not match the type of the
-    /// index, for example if the index is a string and `self` is an array or a
-    /// number. Also returns `None` if the given key does not exist in the map
-    /// or the given index is not within the bounds of the array.
+    /// Returns `None` if `self` is not an array, if the given index is not
+    /// within the bounds of the array, or if the index is a string (a
+    /// `ValueNoObj` never holds an object).
     ///
     /// ```
-    /// # use serde_json::json;
+    /// # use serde_json_extensions::ValueNoObj;
     /// #
-    /// let mut object = json!({ "A": 65, "B": 66, "C": 67 });
-    /// *object.get_mut("A").unwrap() = json!(69);
+    /// let mut array = ValueNoObj::from(vec!["A", "B", "C"]);
+    /// *array.get_mut(2).unwrap() = ValueNoObj::from("D");
+    /// assert_eq!(array, ValueNoObj::from(vec!["A", "B", "D"]));
     ///
-    /// let mut array = json!([ "A", "B", "C" ]);
-    /// *array.get_mut(2).unwrap() = json!("D");
+    /// assert_eq!(array.get_mut(3), None);
+    /// assert_eq!(ValueNoObj::Null.get_mut(0), None);
     /// ```
     pub fn get_mut<I: Index>(&mut self, index: I) -> Option<&mut ValueNoObj> {
         index.index_into_mut(self)
@@ -419,6 +627,53 @@ impl ValueNoObj {
         }
     }
 
+    /// If the `Value` is an Array, returns its elements as a slice. Returns
+    /// None otherwise.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// fn count_nulls(values: &[ValueNoObj]) -> usize {
+    ///     values.iter().filter(|v| v.is_null()).count()
+    /// }
+    ///
+    /// let v = value_no_obj!([null, 1, [null], null]);
+    /// assert_eq!(v.as_slice().map(count_nulls), Some(2));
+    /// assert_eq!(value_no_obj!([]).as_slice(), Some(&[][..]));
+    ///
+    /// // Scalars are not arrays, not even `Null`.
+    /// assert_eq!(ValueNoObj::Null.as_slice(), None);
+    /// assert_eq!(ValueNoObj::from("a").as_slice(), None);
+    /// ```
+    pub fn as_slice(&self) -> Option<&[ValueNoObj]> {
+        match self {
+            ValueNoObj::Array(list) => Some(list),
+            _ => None,
+        }
+    }
+
+    /// If the `Value` is an Array, returns its elements as a mutable slice.
+    /// Returns None otherwise.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// let mut v = value_no_obj!([3, 1, 2]);
+    /// v.as_mut_slice().unwrap().sort_by_key(|n| n.as_i64());
+    /// assert_eq!(v, value_no_obj!([1, 2, 3]));
+    ///
+    /// v.as_mut_slice().unwrap().swap(0, 2);
+    /// assert_eq!(v, value_no_obj!([3, 2, 1]));
+    ///
+    /// assert!(ValueNoObj::from(true).as_mut_slice().is_none());
+    /// ```
+    pub fn as_mut_slice(&mut self) -> Option<&mut [ValueNoObj]> {
+        match self {
+            ValueNoObj::Array(list) => Some(list),
+            _ => None,
+        }
+    }
+
     /// Returns true if the `Value` is a String. Returns false otherwise.
     ///
     /// For any Value on which `is_string` returns true, `as_str` is guaranteed
@@ -491,16 +746,17 @@ impl ValueNoObj {
     /// None otherwise.
     ///
     /// ```
-    /// # use serde_json::{json, Number};
+    /// # use serde_json_extensions::{value_no_obj, Number};
     /// #
-    /// let v = json!({ "a": 1, "b": 2.2, "c": -3, "d": "4" });
+    /// let v = value_no_obj!([1, 2.2, -3, "4", [5]]);
     ///
-    /// assert_eq!(v["a"].as_number(), Some(&Number::from(1u64)));
-    /// assert_eq!(v["b"].as_number(), Some(&Number::from_f64(2.2).unwrap()));
-    /// assert_eq!(v["c"].as_number(), Some(&Number::from(-3i64)));
+    /// assert_eq!(v[0].as_number(), Some(&Number::from(1u64)));
+    /// assert_eq!(v[1].as_number(), Some(&Number::from_f64(2.2).unwrap()));
+    /// assert_eq!(v[2].as_number(), Some(&Number::from(-3i64)));
     ///
-    /// // The string `"4"` is not a number.
-    /// assert_eq!(v["d"].as_number(), None);
+    /// // The string `"4"` and the array `[5]` are not numbers.
+    /// assert_eq!(v[3].as_number(), None);
+    /// assert_eq!(v[4].as_number(), None);
     /// ```
     pub fn as_number(&self) -> Option<&Number> {
         match self {
@@ -509,6 +765,30 @@ impl ValueNoObj {
         }
     }
 
+    /// If the `Value` is a Number, returns the [`Number`] by value. Returns
+    /// None otherwise.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, Number, ValueNoObj};
+    /// #
+    /// let total = value_no_obj!([1, 2.5, "x", null])
+    ///     .into_iter()
+    ///     .filter_map(ValueNoObj::into_number)
+    ///     .filter_map(|n| n.as_f64())
+    ///     .sum::<f64>();
+    /// assert_eq!(total, 3.5);
+    ///
+    /// assert_eq!(ValueNoObj::from(7).into_number(), Some(Number::from(7)));
+    /// assert_eq!(ValueNoObj::from("7").into_number(), None);
+    /// assert_eq!(value_no_obj!([7]).into_number(), None);
+    /// ```
+    pub fn into_number(self) -> Option<Number> {
+        match self {
+            ValueNoObj::Number(number) => Some(number),
+            _ => None,
+        }
+    }
+
     /// Returns true if the `Value` is an integer between `i64::MIN` and
     /// `i64::MAX`.
     ///
@@ -626,6 +906,141 @@ impl ValueNoObj {
         }
     }
 
+    /// If the `ValueNoObj` is an integer, represent it as i128 if possible. Returns
+    /// None otherwise.
+    ///
+    /// Integers outside the `i64`/`u64` range are only preserved when the
+    /// `arbitrary_precision` feature is enabled; see [`Number::as_i128`].
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObj;
+    /// #
+    /// let v: ValueNoObj = "-18446744073709551616".parse().unwrap();
+    /// if cfg!(feature = "arbitrary_precision") {
+    ///     assert_eq!(v.as_i128(), Some(-(1_i128 << 64)));
+    /// } else {
+    ///     assert_eq!(v.as_i128(), None);
+    /// }
+    ///
+    /// let v: ValueNoObj = "[-170141183460469231731687303715884105728, 170141183460469231731687303715884105728]"
+    ///     .parse()
+    ///     .unwrap();
+    /// if cfg!(feature = "arbitrary_precision") {
+    ///     assert_eq!(v[0].as_i128(), Some(i128::MIN));
+    /// } else {
+    ///     // Parsed as an f64, which is not an integer.
+    ///     assert!(v[0].is_f64());
+    ///     assert_eq!(v[0].as_i128(), None);
+    /// }
+    /// // One past `i128::MAX` is out of range either way.
+    /// assert_eq!(v[1].as_i128(), None);
+    ///
+    /// assert_eq!(ValueNoObj::from(-64).as_i128(), Some(-64));
+    /// assert_eq!(ValueNoObj::from("64").as_i128(), None);
+    /// ```
+    pub fn as_i128(&self) -> Option<i128> {
+        match self {
+            ValueNoObj::Number(n) => n.as_i128(),
+            _ => None,
+        }
+    }
+
+    /// If the `ValueNoObj` is an integer, represent it as u128 if possible. Returns
+    /// None otherwise.
+    ///
+    /// Integers above `u64::MAX` are only preserved when the
+    /// `arbitrary_precision` feature is enabled; see [`Number::as_u128`].
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObj;
+    /// #
+    /// let v: ValueNoObj = "18446744073709551616".parse().unwrap();
+    /// if cfg!(feature = "arbitrary_precision") {
+    ///     assert_eq!(v.as_u128(), Some(1_u128 << 64));
+    /// } else {
+    ///     assert_eq!(v.as_u128(), None);
+    /// }
+    ///
+    /// let v: ValueNoObj = "340282366920938463463374607431768211455".parse().unwrap();
+    /// if cfg!(feature = "arbitrary_precision") {
+    ///     assert_eq!(v.as_u128(), Some(u128::MAX));
+    ///     assert_eq!(v.to_string(), u128::MAX.to_string());
+    /// } else {
+    ///     assert_eq!(v.as_u128(), None);
+    /// }
+    ///
+    /// assert_eq!(ValueNoObj::from(64).as_u128(), Some(64));
+    /// assert_eq!(ValueNoObj::from(-64).as_u128(), None);
+    /// ```
+    pub fn as_u128(&self) -> Option<u128> {
+        match self {
+            ValueNoObj::Number(n) => n.as_u128(),
+            _ => None,
+        }
+    }
+
+    /// Builds a number from an `i128`, falling back to the nearest `f64`
+    /// when it does not fit in `i64` or `u64`.
+    ///
+    /// **This is lossy**: an `f64` keeps only 53 significant bits, so beyond
+    /// the 64-bit range the integer is rounded and distinct inputs can give
+    /// equal results. Serializing an out-of-range `i128`
+    /// fails with "number out of range" instead; use this only when an
+    /// approximate value is better than an error. With the
+    /// `arbitrary_precision` feature, every `i128` is stored exactly.
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObj;
+    /// #
+    /// assert_eq!(ValueNoObj::from_i128_lossy(-5), -5);
+    /// assert_eq!(ValueNoObj::from_i128_lossy(u64::MAX as i128), u64::MAX);
+    ///
+    /// let big = ValueNoObj::from_i128_lossy(-(u64::MAX as i128) - 1);
+    /// if cfg!(feature = "arbitrary_precision") {
+    ///     assert_eq!(big.as_i128(), Some(-(u64::MAX as i128) - 1));
+    /// } else {
+    ///     assert!(big.is_f64());
+    ///     assert_eq!(big.as_f64(), Some(-18446744073709551616.0));
+    /// }
+    /// ```
+    pub fn from_i128_lossy(n: i128) -> ValueNoObj {
+        ValueNoObj::Number(Number::from_i128_lossy(n))
+    }
+
+    /// Builds a number from a `u128`, falling back to the nearest `f64`
+    /// when it does not fit in `u64`.
+    ///
+    /// **This is lossy** in the same way as
+    /// [`from_i128_lossy`](ValueNoObj::from_i128_lossy): for example
+    /// `u64::MAX + 1` and `u64::MAX + 2` both become
+    /// `18446744073709551616.0`. With the `arbitrary_precision` feature,
+    /// every `u128` is stored exactly.
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObj;
+    /// #
+    /// assert_eq!(ValueNoObj::from_u128_lossy(u64::MAX as u128), u64::MAX);
+    ///
+    /// let one_past = ValueNoObj::from_u128_lossy(u64::MAX as u128 + 1);
+    /// let two_past = ValueNoObj::from_u128_lossy(u64::MAX as u128 + 2);
+    /// if cfg!(feature = "arbitrary_precision") {
+    ///     assert_eq!(one_past.as_u128(), Some(u64::MAX as u128 + 1));
+    ///     assert_ne!(one_past, two_past);
+    /// } else {
+    ///     assert!(one_past.is_f64());
+    ///     assert_eq!(one_past.as_f64(), Some(18446744073709551616.0));
+    ///     assert_eq!(one_past, two_past);
+    ///     assert_eq!(one_past.to_string(), "1.8446744073709552e19");
+    /// }
+    ///
+    /// // Serializing still refuses without `arbitrary_precision`.
+    /// let strict = serde_json_extensions::value_no_obj::to_value(u64::MAX as u128 + 1);
+    /// assert_eq!(strict.is_ok(), cfg!(feature = "arbitrary_precision"));
+    /// ```
+    pub fn from_u128_lossy(n: u128) -> ValueNoObj {
+        ValueNoObj::Number(Number::from_u128_lossy(n))
+    }
+
     /// If the `Value` is a number, represent it as f64 if possible. Returns
     /// None otherwise.
     ///
@@ -645,37 +1060,72 @@ impl ValueNoObj {
         }
     }
 
-    /// Returns true if the `Value` is a Boolean. Returns false otherwise.
+    /// If the `ValueNoObj` is a number that `f64` represents exactly, returns
+    /// it as f64. Returns None otherwise.
+    ///
+    /// See [`Number::as_f64_lossless`].
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// let v = value_no_obj!([9007199254740992u64, 9007199254740993u64, 12, 1.5, "3"]);
+    ///
+    /// assert_eq!(v[0].as_f64_lossless(), Some(9007199254740992.0));
+    /// assert_eq!(v[1].as_f64_lossless(), None);
+    /// assert_eq!(v[1].as_f64(), Some(9007199254740992.0));
+    /// assert_eq!(v[2].as_f64_lossless(), Some(12.0));
+    /// assert_eq!(v[3].as_f64_lossless(), Some(1.5));
+    /// assert_eq!(v[4].as_f64_lossless(), None);
+    /// ```
+    pub fn as_f64_lossless(&self) -> Option<f64> {
+        match self {
+            ValueNoObj::Number(n) => n.as_f64_lossless(),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the `ValueNoObj` is a Boolean. Returns false otherwise.
     ///
-    /// For any Value on which `is_boolean` returns true, `as_bool` is
+    /// For any `ValueNoObj` on which `is_boolean` returns true, `as_bool` is
     /// guaranteed to return the boolean value.
     ///
     /// ```
-    /// # use serde_json::json;
+    /// # use serde_json_extensions::value_no_obj;
     /// #
-    /// let v = json!({ "a": false, "b": "false" });
+    /// let v = value_no_obj!([false, "false"]);
     ///
-    /// assert!(v["a"].is_boolean());
+    /// assert!(v[0].is_boolean());
     ///
     /// // The string `"false"` is a string, not a boolean.
-    /// assert!(!v["b"].is_boolean());
+    /// assert!(!v[1].is_boolean());
     /// ```
     pub fn is_boolean(&self) -> bool {
         self.as_bool().is_some()
     }
 
-    /// If the `Value` is a Boolean, returns the associated bool. Returns None
-    /// otherwise.
+    /// If the `ValueNoObj` is a Boolean, returns the associated bool. Returns
+    /// None otherwise.
+    ///
+    /// This is strict, exactly like `serde_json::Value::as_bool`: only the
+    /// `Bool` variant matches. Nothing is coerced, so the strings `"true"`
+    /// and `"false"`, the numbers `0` and `1`, and `null` all give `None`.
+    /// There is no lenient counterpart; a caller that wants to accept
+    /// spelled-out booleans has to say so.
     ///
     /// ```
-    /// # use serde_json::json;
+    /// # use serde_json_extensions::value_no_obj;
     /// #
-    /// let v = json!({ "a": false, "b": "false" });
+    /// let v = value_no_obj!([false, true, "false", "true", 0, 1, null, [true]]);
     ///
-    /// assert_eq!(v["a"].as_bool(), Some(false));
+    /// assert_eq!(v[0].as_bool(), Some(false));
+    /// assert_eq!(v[1].as_bool(), Some(true));
+    /// for i in 2..8 {
+    ///     assert_eq!(v[i].as_bool(), None);
+    /// }
     ///
-    /// // The string `"false"` is a string, not a boolean.
-    /// assert_eq!(v["b"].as_bool(), None);
+    /// // Opting in to strings explicitly.
+    /// let lenient = v[3].as_bool().or_else(|| v[3].as_str()?.parse().ok());
+    /// assert_eq!(lenient, Some(true));
     /// ```
     pub fn as_bool(&self) -> Option<bool> {
         match *self {
@@ -690,14 +1140,38 @@ impl ValueNoObj {
     /// to return `Some(())`.
     ///
     /// ```
-    /// # use serde_json::json;
+    /// # use serde_json_extensions::value_no_obj;
     /// #
-    /// let v = json!({ "a": null, "b": false });
+    /// let v = value_no_obj!([null, false]);
     ///
-    /// assert!(v["a"].is_null());
+    /// assert!(v[0].is_null());
     ///
     /// // The boolean `false` is not null.
-    /// assert!(!v["b"].is_null());
+    /// assert!(!v[1].is_null());
+    /// ```
+    ///
+    /// Its signature is the one `#[serde(skip_serializing_if = "...")]`
+    /// expects, so a null field can be left out of the output entirely.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Reading {
+    ///     id: u32,
+    ///     #[serde(skip_serializing_if = "ValueNoObj::is_null")]
+    ///     samples: ValueNoObj,
+    /// }
+    ///
+    /// let empty = Reading { id: 1, samples: ValueNoObj::Null };
+    /// let full = Reading { id: 2, samples: value_no_obj!([1.5, null]) };
+    ///
+    /// assert_eq!(serde_json_extensions::to_string(&empty).unwrap(), r#"{"id":1}"#);
+    /// assert_eq!(
+    ///     serde_json_extensions::to_string(&full).unwrap(),
+    ///     r#"{"id":2,"samples":[1.5,null]}"#,
+    /// );
     /// ```
     pub fn is_null(&self) -> bool {
         self.as_null().is_some()
@@ -732,21 +1206,31 @@ impl ValueNoObj {
     /// addressed value is returned and if there is no such value `None` is
     /// returned.
     ///
+    /// Since a `ValueNoObj` never holds an object, every token must be an
+    /// array index. A token that is not a canonical index (leading zeros, a
+    /// `+` sign, or anything non-numeric) resolves to `None`, as does the
+    /// `-` token, which RFC 6901 reserves for the nonexistent element past
+    /// the end of an array.
+    ///
     /// For more information read [RFC6901](https://tools.ietf.org/html/rfc6901).
     ///
     /// # Examples
     ///
     /// ```
-    /// # use serde_json::json;
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
     /// #
-    /// let data = json!({
-    ///     "x": {
-    ///         "y": ["z", "zz"]
-    ///     }
-    /// });
-    ///
-    /// assert_eq!(data.pointer("/x/y/1").unwrap(), &json!("zz"));
-    /// assert_eq!(data.pointer("/a/b/c"), None);
+    /// let data = value_no_obj!([["a"], ["b", "bb"], 3]);
+    ///
+    /// assert_eq!(data.pointer("/1/0").unwrap(), &value_no_obj!("b"));
+    /// assert_eq!(data.pointer("/1"), Some(&value_no_obj!(["b", "bb"])));
+    /// assert_eq!(data.pointer(""), Some(&data));
+    ///
+    /// assert_eq!(data.pointer("/1/2"), None);
+    /// assert_eq!(data.pointer("/2/0"), None);
+    /// assert_eq!(data.pointer("/x"), None);
+    /// assert_eq!(data.pointer("/-"), None);
+    /// assert_eq!(data.pointer("/01"), None);
+    /// assert_eq!(data.pointer("1/0"), None);
     /// ```
     pub fn pointer(&self, pointer: &str) -> Option<&ValueNoObj> {
         if pointer.is_empty() {
@@ -768,39 +1252,34 @@ impl ValueNoObj {
     /// Looks up a value by a JSON Pointer and returns a mutable reference to
     /// that value.
     ///
-    /// JSON Pointer defines a string syntax for identifying a specific value
-    /// within a JavaScript Object Notation (JSON) document.
-    ///
-    /// A Pointer is a Unicode string with the reference tokens separated by `/`.
-    /// Inside tokens `/` is replaced by `~1` and `~` is replaced by `~0`. The
-    /// addressed value is returned and if there is no such value `None` is
-    /// returned.
+    /// Resolution follows the same rules as [`pointer`]: every token must be
+    /// an array index, and `-` resolves to `None`.
     ///
     /// For more information read [RFC6901](https://tools.ietf.org/html/rfc6901).
     ///
+    /// [`pointer`]: ValueNoObj::pointer
+    ///
     /// # Example of Use
     ///
     /// ```
-    /// use serde_json::Value;
-    ///
-    /// fn main() {
-    ///     let s = r#"{"x": 1.0, "y": 2.0}"#;
-    ///     let mut value: Value = serde_json::from_str(s).unwrap();
-    ///
-    ///     // Check value using read-only pointer
-    ///     assert_eq!(value.pointer("/x"), Some(&1.0.into()));
-    ///     // Change value with direct assignment
-    ///     *value.pointer_mut("/x").unwrap() = 1.5.into();
-    ///     // Check that new value was written
-    ///     assert_eq!(value.pointer("/x"), Some(&1.5.into()));
-    ///     // Or change the value only if it exists
-    ///     value.pointer_mut("/x").map(|v| *v = 1.5.into());
-    ///
-    ///     // "Steal" ownership of a value. Can replace with any valid Value.
-    ///     let old_x = value.pointer_mut("/x").map(Value::take).unwrap();
-    ///     assert_eq!(old_x, 1.5);
-    ///     assert_eq!(value.pointer("/x").unwrap(), &Value::Null);
-    /// }
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// let mut value: ValueNoObj = "[[1.0, 2.0], [3.0]]".parse().unwrap();
+    ///
+    /// // Check value using read-only pointer
+    /// assert_eq!(value.pointer("/0/1"), Some(&2.0.into()));
+    /// // Change value with direct assignment
+    /// *value.pointer_mut("/0/1").unwrap() = 2.5.into();
+    /// // Check that new value was written
+    /// assert_eq!(value.pointer("/0/1"), Some(&2.5.into()));
+    ///
+    /// // "Steal" ownership of a value. Can replace with any valid ValueNoObj.
+    /// let old = value.pointer_mut("/1/0").map(ValueNoObj::take).unwrap();
+    /// assert_eq!(old, 3.0);
+    /// assert_eq!(value, value_no_obj!([[1.0, 2.5], [null]]));
+    ///
+    /// assert!(value.pointer_mut("/1/-").is_none());
+    /// assert!(value.pointer_mut("/0/x").is_none());
     /// ```
     pub fn pointer_mut(&mut self, pointer: &str) -> Option<&mut ValueNoObj> {
         if pointer.is_empty() {
@@ -831,39 +1310,1148 @@ impl ValueNoObj {
     pub fn take(&mut self) -> ValueNoObj {
         mem::replace(self, ValueNoObj::Null)
     }
-}
 
-/// The default value is `Value::Null`.
-///
-/// This is useful for handling omitted `Value` fields when deserializing.
-///
-/// # Examples
-///
-/// ```
-/// # use serde::Deserialize;
-/// use serde_json::Value;
-///
-/// #[derive(Deserialize)]
-/// struct Settings {
-///     level: i32,
-///     #[serde(default)]
-///     extras: Value,
-/// }
-///
-/// # fn try_main() -> Result<(), serde_json::Error> {
-/// let data = r#" { "level": 42 } "#;
-/// let s: Settings = serde_json::from_str(data)?;
-///
-/// assert_eq!(s.level, 42);
-/// assert_eq!(s.extras, Value::Null);
-/// #
-/// #     Ok(())
-/// # }
-/// #
-/// # try_main().unwrap()
-/// ```
-impl Default for ValueNoObj {
-    fn default() -> ValueNoObj {
+    /// Returns the number of elements of a `ValueNoObj::Array`.
+    ///
+    /// Every other variant has length 0, matching what
+    /// [`iter`](ValueNoObj::iter) yields for it.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// assert_eq!(value_no_obj!([1, [2, 3], null]).len(), 3);
+    /// assert_eq!(value_no_obj!([]).len(), 0);
+    ///
+    /// assert_eq!(ValueNoObj::from("abc").len(), 0);
+    /// assert_eq!(ValueNoObj::Null.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        match self {
+            ValueNoObj::Array(list) => list.len(),
+            _ => 0,
+        }
+    }
+
+    /// Returns `true` if this is an array with no elements, or any other
+    /// variant.
+    ///
+    /// Scalars count as empty because their [`len`](ValueNoObj::len) is 0;
+    /// use [`is_array`](ValueNoObj::is_array) to tell them apart from `[]`.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// assert!(value_no_obj!([]).is_empty());
+    /// assert!(!value_no_obj!([[]]).is_empty());
+    ///
+    /// assert!(ValueNoObj::from(0).is_empty());
+    /// assert!(ValueNoObj::from(true).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the value as a flat stream of [`JsonToken`]s, in the order
+    /// they would be written as JSON.
+    ///
+    /// A scalar yields a single token. An array yields `ArrayStart`, the
+    /// tokens of each element in turn, then `ArrayEnd`.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, Number, ValueNoObj};
+    /// use serde_json_extensions::value_no_obj::JsonToken;
+    ///
+    /// let v = value_no_obj!([1, "x"]);
+    /// let one = Number::from(1);
+    /// assert_eq!(
+    ///     v.tokens().collect::<Vec<_>>(),
+    ///     [
+    ///         JsonToken::ArrayStart,
+    ///         JsonToken::Number(&one),
+    ///         JsonToken::String("x"),
+    ///         JsonToken::ArrayEnd,
+    ///     ],
+    /// );
+    ///
+    /// let v = value_no_obj!([[], [null, [true]]]);
+    /// let kinds: Vec<&str> = v
+    ///     .tokens()
+    ///     .map(|token| match token {
+    ///         JsonToken::ArrayStart => "[",
+    ///         JsonToken::ArrayEnd => "]",
+    ///         JsonToken::Null => "null",
+    ///         JsonToken::Bool(_) => "bool",
+    ///         JsonToken::Number(_) | JsonToken::String(_) => "scalar",
+    ///     })
+    ///     .collect();
+    /// assert_eq!(kinds, ["[", "[", "]", "[", "null", "[", "bool", "]", "]", "]"]);
+    ///
+    /// assert_eq!(ValueNoObj::from("s").tokens().collect::<Vec<_>>(), [JsonToken::String("s")]);
+    /// ```
+    pub fn tokens(&self) -> Tokens<'_> {
+        Tokens::new(self)
+    }
+
+    /// Returns an iterator over the elements of a `ValueNoObj::Array`.
+    ///
+    /// Any other variant yields nothing rather than panicking. The same
+    /// iterator is produced by `for element in &value`.
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObj;
+    /// #
+    /// let v = ValueNoObj::from(vec![1, 2, 3]);
+    /// let doubled: Vec<i64> = v.iter().map(|x| x.as_i64().unwrap() * 2).collect();
+    /// assert_eq!(doubled, [2, 4, 6]);
+    ///
+    /// let mut count = 0;
+    /// for _ in &ValueNoObj::Null {
+    ///     count += 1;
+    /// }
+    /// assert_eq!(count, 0);
+    /// ```
+    pub fn iter(&self) -> Iter<'_> {
+        Iter::new(self)
+    }
+
+    /// Returns an iterator that allows modifying each element of a
+    /// `ValueNoObj::Array`.
+    ///
+    /// Any other variant yields nothing rather than panicking. The same
+    /// iterator is produced by `for element in &mut value`.
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObj;
+    /// #
+    /// let mut v = ValueNoObj::from(vec![1, 2, 3]);
+    /// for x in v.iter_mut() {
+    ///     *x = ValueNoObj::from(x.as_i64().unwrap() + 1);
+    /// }
+    /// assert_eq!(v, ValueNoObj::from(vec![2, 3, 4]));
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_> {
+        IterMut::new(self)
+    }
+
+    /// Reverses the order of elements of a `ValueNoObj::Array` in place, like
+    /// `Vec::reverse`. Does nothing if the value is not an array.
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObj;
+    /// #
+    /// let mut v = ValueNoObj::from(vec![1, 2, 3]);
+    /// v.reverse();
+    /// assert_eq!(v, ValueNoObj::from(vec![3, 2, 1]));
+    ///
+    /// let mut s = ValueNoObj::from("abc");
+    /// s.reverse();
+    /// assert_eq!(s, "abc");
+    /// ```
+    pub fn reverse(&mut self) {
+        if let ValueNoObj::Array(list) = self {
+            list.reverse();
+        }
+    }
+
+    /// Keeps only the elements of a `ValueNoObj::Array` for which `f`
+    /// returns `true`, like `Vec::retain`. Does nothing if the value is not
+    /// an array.
+    ///
+    /// Only the top level is filtered; nested arrays are passed to `f` whole.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// let mut v = value_no_obj!([1, "a", null, ["b"], "c", true]);
+    /// v.retain(ValueNoObj::is_string);
+    /// assert_eq!(v, value_no_obj!(["a", "c"]));
+    ///
+    /// let mut s = ValueNoObj::from("a");
+    /// s.retain(|_| false);
+    /// assert_eq!(s, "a");
+    /// ```
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&ValueNoObj) -> bool,
+    {
+        if let ValueNoObj::Array(list) = self {
+            list.retain(f);
+        }
+    }
+
+    /// Removes every `Null` element from this array and, recursively, from
+    /// every array nested in it, in place.
+    ///
+    /// The remaining elements keep their order. Arrays left empty are kept,
+    /// and a value that is itself `Null`, or any other scalar, is unchanged.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// let mut v = value_no_obj!([1, null, [null, 2]]);
+    /// v.remove_nulls();
+    /// assert_eq!(v, value_no_obj!([1, [2]]));
+    ///
+    /// let mut v = value_no_obj!([[null], "a", [[null, null, false], null]]);
+    /// v.remove_nulls();
+    /// assert_eq!(v, value_no_obj!([[], "a", [[false]]]));
+    ///
+    /// let mut n = ValueNoObj::Null;
+    /// n.remove_nulls();
+    /// assert_eq!(n, ValueNoObj::Null);
+    /// ```
+    pub fn remove_nulls(&mut self) {
+        let mut stack = Vec::new();
+        stack.push(self);
+        while let Some(value) = stack.pop() {
+            if let ValueNoObj::Array(list) = value {
+                list.retain(|element| !element.is_null());
+                stack.extend(list.iter_mut());
+            }
+        }
+    }
+
+    /// Replaces every non-array value, at any depth, with `f` applied to it.
+    ///
+    /// Leaves are visited in document order. Whatever `f` returns is kept as
+    /// is, so an array returned in place of a leaf is not visited again. A
+    /// value that is not an array is itself the only leaf.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// let double = |leaf: ValueNoObj| match leaf.as_i64() {
+    ///     Some(n) => ValueNoObj::from(n * 2),
+    ///     None => leaf,
+    /// };
+    ///
+    /// let v = value_no_obj!([1, [2, "x", [3, null]], [], 4]);
+    /// assert_eq!(v.map_leaves(double), value_no_obj!([2, [4, "x", [6, null]], [], 8]));
+    ///
+    /// assert_eq!(ValueNoObj::from(21).map_leaves(double), 42);
+    ///
+    /// let trimmed = value_no_obj!([" a ", [" b"]]).map_leaves(|leaf| match leaf.as_str() {
+    ///     Some(s) => ValueNoObj::from(s.trim()),
+    ///     None => leaf,
+    /// });
+    /// assert_eq!(trimmed, value_no_obj!(["a", ["b"]]));
+    ///
+    /// let mut seen = Vec::new();
+    /// let wrapped = value_no_obj!([1, [2], 3]).map_leaves(|leaf| {
+    ///     seen.push(leaf.clone());
+    ///     value_no_obj!([leaf])
+    /// });
+    /// assert_eq!(seen, [1, 2, 3]);
+    /// assert_eq!(wrapped, value_no_obj!([[1], [[2]], [3]]));
+    /// ```
+    pub fn map_leaves<F>(mut self, mut f: F) -> ValueNoObj
+    where
+        F: FnMut(ValueNoObj) -> ValueNoObj,
+    {
+        let mut stack = Vec::new();
+        stack.push(&mut self);
+        while let Some(value) = stack.pop() {
+            match value {
+                // Pushed in reverse so the first element is popped first.
+                ValueNoObj::Array(list) => stack.extend(list.iter_mut().rev()),
+                leaf => *leaf = f(leaf.take()),
+            }
+        }
+        self
+    }
+
+    /// Flattens nested arrays into a single array of their non-array
+    /// values, in document order. Empty arrays contribute nothing.
+    ///
+    /// A value that is not an array becomes a one-element array.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// let v = value_no_obj!([[1, 2], [3, [4]]]);
+    /// assert_eq!(v.flatten(), value_no_obj!([1, 2, 3, 4]));
+    ///
+    /// let pages = value_no_obj!([["a", "b"], [], ["c"]]);
+    /// assert_eq!(pages.flatten(), value_no_obj!(["a", "b", "c"]));
+    ///
+    /// assert_eq!(ValueNoObj::from("x").flatten(), value_no_obj!(["x"]));
+    /// assert_eq!(ValueNoObj::Null.flatten(), value_no_obj!([null]));
+    /// ```
+    pub fn flatten(self) -> ValueNoObj {
+        self.flatten_depth(usize::MAX)
+    }
+
+    /// Like [`flatten`](ValueNoObj::flatten), but only splices the elements
+    /// of arrays nested at most `depth` levels inside `self`. Deeper arrays
+    /// are kept whole, and a `depth` of 0 leaves an array unchanged.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// let v = value_no_obj!([[1, 2], [3, [4]]]);
+    /// assert_eq!(v.clone().flatten_depth(0), v);
+    /// assert_eq!(v.clone().flatten_depth(1), value_no_obj!([1, 2, 3, [4]]));
+    /// assert_eq!(v.clone().flatten_depth(2), value_no_obj!([1, 2, 3, 4]));
+    ///
+    /// assert_eq!(ValueNoObj::from(1).flatten_depth(0), value_no_obj!([1]));
+    /// ```
+    pub fn flatten_depth(self, depth: usize) -> ValueNoObj {
+        let list = match self {
+            ValueNoObj::Array(list) => list,
+            scalar => return ValueNoObj::Array(Vec::from([scalar])),
+        };
+        let mut flat = Vec::with_capacity(list.len());
+        let mut stack = Vec::new();
+        stack.push((list.into_iter(), depth));
+        while let Some((iter, remaining)) = stack.last_mut() {
+            match iter.next() {
+                Some(ValueNoObj::Array(inner)) if *remaining > 0 => {
+                    let remaining = *remaining - 1;
+                    stack.push((inner.into_iter(), remaining));
+                }
+                Some(value) => flat.push(value),
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+        ValueNoObj::Array(flat)
+    }
+
+    /// Counts the values of each kind in `self`, itself included, at any
+    /// depth.
+    ///
+    /// The keys are `"null"`, `"boolean"`, `"number"`, `"string"` and
+    /// `"array"`, the names used in panic messages, and only kinds that occur
+    /// are present.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// let v = value_no_obj!([1, "a", [2.5, null, ["b", true]], [], null]);
+    /// let histogram = v.type_histogram();
+    ///
+    /// assert_eq!(histogram["array"], 4);
+    /// assert_eq!(histogram["number"], 2);
+    /// assert_eq!(histogram["string"], 2);
+    /// assert_eq!(histogram["null"], 2);
+    /// assert_eq!(histogram["boolean"], 1);
+    /// assert_eq!(histogram.values().sum::<usize>(), 11);
+    ///
+    /// let scalar = ValueNoObj::from("x").type_histogram();
+    /// assert_eq!(scalar.into_iter().collect::<Vec<_>>(), [("string", 1)]);
+    /// ```
+    pub fn type_histogram(&self) -> BTreeMap<&'static str, usize> {
+        let mut histogram = BTreeMap::new();
+        let mut stack = Vec::new();
+        stack.push(self);
+        while let Some(value) = stack.pop() {
+            let kind = match value {
+                ValueNoObj::Null => "null",
+                ValueNoObj::Bool(_) => "boolean",
+                ValueNoObj::Number(_) => "number",
+                ValueNoObj::String(_) => "string",
+                ValueNoObj::Array(list) => {
+                    stack.extend(list);
+                    "array"
+                }
+            };
+            *histogram.entry(kind).or_insert(0) += 1;
+        }
+        histogram
+    }
+
+    /// Resizes a `ValueNoObj::Array` in place so that its length is equal to
+    /// `new_len`, like `Vec::resize`.
+    ///
+    /// If `new_len` is greater than the current length, the array is extended
+    /// by the difference, with each additional slot filled with `value`. If
+    /// `new_len` is less than the current length, the array is truncated.
+    ///
+    /// Returns an error and leaves `self` untouched if it is not an array.
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObj;
+    /// #
+    /// let mut v = ValueNoObj::from(vec![1, 2]);
+    ///
+    /// v.resize(4, ValueNoObj::Null).unwrap();
+    /// assert_eq!(v, ValueNoObj::from(vec![Some(1), Some(2), None, None]));
+    ///
+    /// v.resize(1, ValueNoObj::Null).unwrap();
+    /// assert_eq!(v, ValueNoObj::from(vec![1]));
+    ///
+    /// assert!(ValueNoObj::from(1).resize(2, ValueNoObj::Null).is_err());
+    /// ```
+    pub fn resize(&mut self, new_len: usize, value: ValueNoObj) -> Result<(), Error> {
+        match self {
+            ValueNoObj::Array(list) => {
+                list.resize(new_len, value);
+                Ok(())
+            }
+            _ => Err(self.invalid_type(&"array")),
+        }
+    }
+
+    /// Swaps two elements of a `ValueNoObj::Array`, like `Vec::swap`.
+    ///
+    /// Fails if the value is not an array or if either index is out of
+    /// bounds; the array is left unchanged in that case.
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObj;
+    /// #
+    /// let mut v = ValueNoObj::from(vec!["a", "b", "c"]);
+    /// v.swap(0, 2).unwrap();
+    /// assert_eq!(v, ValueNoObj::from(vec!["c", "b", "a"]));
+    ///
+    /// let err = v.swap(1, 3).unwrap_err();
+    /// assert_eq!(err.to_string(), "index 3 out of range for array of length 3");
+    ///
+    /// assert!(ValueNoObj::from("a").swap(0, 0).is_err());
+    /// ```
+    pub fn swap(&mut self, a: usize, b: usize) -> Result<(), Error> {
+        match self {
+            ValueNoObj::Array(list) => {
+                let len = list.len();
+                if a >= len || b >= len {
+                    return Err(ValueNoObj::index_out_of_range(a.max(b), len));
+                }
+                list.swap(a, b);
+                Ok(())
+            }
+            _ => Err(self.invalid_type(&"array")),
+        }
+    }
+
+    /// Inserts an element into a `ValueNoObj::Array` at position `index`,
+    /// shifting all elements after it to the right, like `Vec::insert`.
+    ///
+    /// `index` may equal the length of the array, which appends. Fails if the
+    /// value is not an array or if `index` is greater than the length.
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObj;
+    /// #
+    /// let mut v = ValueNoObj::from(vec![2, 4]);
+    /// v.insert(0, ValueNoObj::from(1)).unwrap();
+    /// v.insert(2, ValueNoObj::from(3)).unwrap();
+    /// v.insert(4, ValueNoObj::from(5)).unwrap();
+    /// assert_eq!(v, ValueNoObj::from(vec![1, 2, 3, 4, 5]));
+    ///
+    /// let err = v.insert(6, ValueNoObj::Null).unwrap_err();
+    /// assert_eq!(err.to_string(), "index 6 out of range for array of length 5");
+    ///
+    /// assert!(ValueNoObj::from(true).insert(0, ValueNoObj::Null).is_err());
+    /// ```
+    pub fn insert(&mut self, index: usize, value: ValueNoObj) -> Result<(), Error> {
+        match self {
+            ValueNoObj::Array(list) if index <= list.len() => {
+                list.insert(index, value);
+                Ok(())
+            }
+            ValueNoObj::Array(list) => Err(ValueNoObj::index_out_of_range(index, list.len())),
+            _ => Err(self.invalid_type(&"array")),
+        }
+    }
+
+    /// Removes an element from a `ValueNoObj::Array` and returns it, replacing
+    /// it with the last element, like `Vec::swap_remove`.
+    ///
+    /// This does not preserve ordering but is O(1). Fails if the value is not
+    /// an array or if `index` is out of bounds.
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObj;
+    /// #
+    /// let mut v = ValueNoObj::from(vec![1, 2, 3, 4]);
+    /// assert_eq!(v.swap_remove(1).unwrap(), 2);
+    /// assert_eq!(v, ValueNoObj::from(vec![1, 4, 3]));
+    ///
+    /// let err = v.swap_remove(3).unwrap_err();
+    /// assert_eq!(err.to_string(), "index 3 out of range for array of length 3");
+    ///
+    /// assert!(ValueNoObj::Null.swap_remove(0).is_err());
+    /// ```
+    pub fn swap_remove(&mut self, index: usize) -> Result<ValueNoObj, Error> {
+        match self {
+            ValueNoObj::Array(list) if index < list.len() => Ok(list.swap_remove(index)),
+            ValueNoObj::Array(list) => Err(ValueNoObj::index_out_of_range(index, list.len())),
+            _ => Err(self.invalid_type(&"array")),
+        }
+    }
+
+    /// Consumes a `ValueNoObj::Array` of numbers and returns its elements as
+    /// `f64`.
+    ///
+    /// If the value is not an array, or any element is not a number, the
+    /// original value is handed back unchanged in the `Err` variant.
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObj;
+    /// #
+    /// let v = ValueNoObj::from(vec![1.5, 2.0, -3.25]);
+    /// assert_eq!(v.into_f64_vec(), Ok(vec![1.5, 2.0, -3.25]));
+    ///
+    /// let mixed = ValueNoObj::from(vec![ValueNoObj::from(1), ValueNoObj::from("2")]);
+    /// assert_eq!(mixed.clone().into_f64_vec(), Err(mixed));
+    /// ```
+    pub fn into_f64_vec(self) -> Result<Vec<f64>, ValueNoObj> {
+        match self {
+            ValueNoObj::Array(list) if list.iter().all(|element| element.as_f64().is_some()) => {
+                Ok(list.iter().filter_map(ValueNoObj::as_f64).collect())
+            }
+            other => Err(other),
+        }
+    }
+
+    /// Consumes a `ValueNoObj::Array` of strings and returns its elements
+    /// without copying them.
+    ///
+    /// If the value is not an array, or any element is not a string, the
+    /// original value is handed back unchanged in the `Err` variant.
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObj;
+    /// #
+    /// let v = ValueNoObj::from(vec!["a", "b"]);
+    /// assert_eq!(v.into_string_vec(), Ok(vec!["a".to_owned(), "b".to_owned()]));
+    ///
+    /// let mixed = ValueNoObj::from(vec![ValueNoObj::from("a"), ValueNoObj::Null]);
+    /// assert_eq!(mixed.clone().into_string_vec(), Err(mixed));
+    ///
+    /// assert_eq!(ValueNoObj::from("a").into_string_vec(), Err(ValueNoObj::from("a")));
+    /// ```
+    pub fn into_string_vec(self) -> Result<Vec<String>, ValueNoObj> {
+        match self {
+            ValueNoObj::Array(list) if list.iter().all(ValueNoObj::is_string) => Ok(list
+                .into_iter()
+                .filter_map(|element| match element {
+                    ValueNoObj::String(s) => Some(s),
+                    _ => None,
+                })
+                .collect()),
+            other => Err(other),
+        }
+    }
+
+    /// Joins an array of strings into one `String`, placing `sep` between
+    /// each pair of elements.
+    ///
+    /// Returns `None` if this is not an array or any element is not a
+    /// string. An empty array joins to the empty string.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// let v = value_no_obj!(["a", "b"]);
+    /// assert_eq!(v.join(",").as_deref(), Some("a,b"));
+    /// assert_eq!(v.join("").as_deref(), Some("ab"));
+    ///
+    /// assert_eq!(value_no_obj!([]).join(", ").as_deref(), Some(""));
+    /// assert_eq!(value_no_obj!(["a", 1]).join(","), None);
+    /// assert_eq!(value_no_obj!(["a", ["b"]]).join(","), None);
+    /// assert_eq!(ValueNoObj::from("a").join(","), None);
+    /// ```
+    pub fn join(&self, sep: &str) -> Option<String> {
+        let list = match self {
+            ValueNoObj::Array(list) => list,
+            _ => return None,
+        };
+        let mut joined = String::new();
+        for (i, element) in list.iter().enumerate() {
+            if i > 0 {
+                joined.push_str(sep);
+            }
+            match element {
+                ValueNoObj::String(s) => joined.push_str(s),
+                _ => return None,
+            }
+        }
+        Some(joined)
+    }
+
+    /// Flattens a rectangular nested numeric array into its shape and its
+    /// elements in row-major order, ready for a numeric library.
+    ///
+    /// Every array at the same depth must have the same length, and every
+    /// leaf must be a number at the innermost depth. A lone number has the
+    /// empty shape.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// let m = value_no_obj!([[1, 2], [3, 4.5]]);
+    /// let (shape, data) = m.into_flat_f64().unwrap();
+    /// assert_eq!(shape, [2, 2]);
+    /// assert_eq!(data, [1.0, 2.0, 3.0, 4.5]);
+    ///
+    /// let (shape, data) = value_no_obj!([[], []]).into_flat_f64().unwrap();
+    /// assert_eq!(shape, [2, 0]);
+    /// assert!(data.is_empty());
+    ///
+    /// let (shape, data) = ValueNoObj::from(7).into_flat_f64().unwrap();
+    /// assert!(shape.is_empty());
+    /// assert_eq!(data, [7.0]);
+    ///
+    /// let err = value_no_obj!([[1, 2], [3]]).into_flat_f64().unwrap_err();
+    /// assert_eq!(err.to_string(), "ragged array: expected 2 elements, found 1");
+    ///
+    /// let err = value_no_obj!([[1, 2], 3]).into_flat_f64().unwrap_err();
+    /// assert!(err.to_string().ends_with(", expected array"));
+    ///
+    /// let err = value_no_obj!([[1, "2"]]).into_flat_f64().unwrap_err();
+    /// assert_eq!(err.to_string(), "invalid type: string \"2\", expected number");
+    ///
+    /// // The first element at each depth suggests a shape of 100,000 to the
+    /// // fourth power, but every other slot is empty, so the array is ragged
+    /// // and nothing that large is ever allocated.
+    /// let n = 100_000;
+    /// let mut v = ValueNoObj::Array(vec![ValueNoObj::from(0); n]);
+    /// for _ in 0..3 {
+    ///     let mut list = vec![ValueNoObj::Array(Vec::new()); n];
+    ///     list[0] = v;
+    ///     v = ValueNoObj::Array(list);
+    /// }
+    /// let err = v.into_flat_f64().unwrap_err();
+    /// assert_eq!(err.to_string(), "ragged array: expected 100000 elements, found 0");
+    /// ```
+    pub fn into_flat_f64(self) -> Result<(Vec<usize>, Vec<f64>), Error> {
+        // The first element at each depth determines the shape; everything
+        // else is checked against it below.
+        let mut shape = Vec::new();
+        let mut first = &self;
+        while let ValueNoObj::Array(list) = first {
+            shape.push(list.len());
+            match list.first() {
+                Some(element) => first = element,
+                None => break,
+            }
+        }
+
+        // Grown as elements are checked rather than reserved up front, since
+        // the shape is only a guess until the whole array has been walked.
+        let mut data = Vec::new();
+        let mut stack = Vec::new();
+        stack.push((&self, 0));
+        while let Some((value, depth)) = stack.pop() {
+            match (value, shape.get(depth)) {
+                (ValueNoObj::Array(list), Some(&len)) => {
+                    if list.len() != len {
+                        return Err(serde::de::Error::custom(format_args!(
+                            "ragged array: expected {} elements, found {}",
+                            len,
+                            list.len()
+                        )));
+                    }
+                    // Pushed in reverse so the first element is popped first.
+                    stack.extend(list.iter().rev().map(|element| (element, depth + 1)));
+                }
+                (_, Some(_)) => return Err(value.invalid_type(&"array")),
+                (ValueNoObj::Number(n), None) => match n.as_f64() {
+                    Some(n) => data.push(n),
+                    None => return Err(value.invalid_type(&"number")),
+                },
+                (_, None) => return Err(value.invalid_type(&"number")),
+            }
+        }
+        Ok((shape, data))
+    }
+
+    /// Returns the largest number found in this value or, recursively, in any
+    /// nested array. Returns `None` if there are no numbers.
+    ///
+    /// Non-numeric elements are skipped.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// let v = value_no_obj!([3, [1, 9], "10", 4]);
+    /// assert_eq!(v.max_number(), Some(9.0));
+    ///
+    /// assert_eq!(ValueNoObj::from(-2.5).max_number(), Some(-2.5));
+    /// assert_eq!(value_no_obj!([null, ["x"]]).max_number(), None);
+    /// ```
+    pub fn max_number(&self) -> Option<f64> {
+        self.fold_numbers(f64::max)
+    }
+
+    /// Returns the smallest number found in this value or, recursively, in
+    /// any nested array. Returns `None` if there are no numbers.
+    ///
+    /// Non-numeric elements are skipped.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// let v = value_no_obj!([3, [1, 9], 4]);
+    /// assert_eq!(v.min_number(), Some(1.0));
+    ///
+    /// assert_eq!(ValueNoObj::Array(Vec::new()).min_number(), None);
+    /// ```
+    pub fn min_number(&self) -> Option<f64> {
+        self.fold_numbers(f64::min)
+    }
+
+    fn fold_numbers(&self, f: fn(f64, f64) -> f64) -> Option<f64> {
+        let mut acc = None;
+        let mut stack = Vec::new();
+        stack.push(self);
+        while let Some(value) = stack.pop() {
+            match value {
+                ValueNoObj::Number(n) => {
+                    if let Some(n) = n.as_f64() {
+                        acc = Some(acc.map_or(n, |acc| f(acc, n)));
+                    }
+                }
+                ValueNoObj::Array(list) => stack.extend(list),
+                _ => {}
+            }
+        }
+        acc
+    }
+
+    /// Returns every scalar leaf paired with its JSON Pointer, in document
+    /// order.
+    ///
+    /// Each path can be passed back to [`pointer`](ValueNoObj::pointer) to
+    /// reach the same leaf. A scalar at the top level has the empty path, and
+    /// empty arrays contribute no leaves.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// let v = value_no_obj!([1, [2]]);
+    /// let paths = v.paths();
+    /// assert_eq!(paths.len(), 2);
+    /// assert_eq!(paths[0], ("/0".to_owned(), &ValueNoObj::from(1)));
+    /// assert_eq!(paths[1], ("/1/0".to_owned(), &ValueNoObj::from(2)));
+    ///
+    /// for (path, leaf) in v.paths() {
+    ///     assert_eq!(v.pointer(&path), Some(leaf));
+    /// }
+    ///
+    /// assert_eq!(ValueNoObj::from("x").paths(), [("".to_owned(), &ValueNoObj::from("x"))]);
+    /// assert!(value_no_obj!([[], [[]]]).paths().is_empty());
+    /// ```
+    pub fn paths(&self) -> Vec<(String, &ValueNoObj)> {
+        let mut paths = Vec::new();
+        let mut stack = Vec::new();
+        stack.push((String::new(), self));
+        while let Some((path, value)) = stack.pop() {
+            match value {
+                ValueNoObj::Array(list) => {
+                    // Pushed in reverse so the first element is popped first.
+                    for (i, element) in list.iter().enumerate().rev() {
+                        stack.push((child_path(&path, i), element));
+                    }
+                }
+                _ => paths.push((path, value)),
+            }
+        }
+        paths
+    }
+
+    /// Reports where two values differ, as JSON Pointer paths paired with
+    /// the differing parts of `self` and `other`, in document order.
+    ///
+    /// Arrays of equal length are compared element by element. Where the
+    /// variants differ, or two arrays differ in length, the whole subtrees at
+    /// that path are reported rather than descending into them. Equal values
+    /// produce no entries.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// let a = value_no_obj!([1, [2, [3, 4]], "x"]);
+    /// let b = value_no_obj!([1, [2, [3, 5]], "x"]);
+    /// assert_eq!(
+    ///     a.diff(&b),
+    ///     [("/1/1/1".to_owned(), &ValueNoObj::from(4), &ValueNoObj::from(5))],
+    /// );
+    ///
+    /// assert!(a.diff(&a.clone()).is_empty());
+    ///
+    /// // A length mismatch is reported at the array itself.
+    /// let c = value_no_obj!([1, [2], "y"]);
+    /// let diff = a.diff(&c);
+    /// assert_eq!(diff.len(), 2);
+    /// assert_eq!(diff[0], ("/1".to_owned(), &a[1], &c[1]));
+    /// assert_eq!(diff[1], ("/2".to_owned(), &a[2], &c[2]));
+    ///
+    /// let f = ValueNoObj::from(false);
+    /// let top = ValueNoObj::Null.diff(&f);
+    /// assert_eq!(top, [(String::new(), &ValueNoObj::Null, &f)]);
+    /// ```
+    pub fn diff<'a>(
+        &'a self,
+        other: &'a ValueNoObj,
+    ) -> Vec<(String, &'a ValueNoObj, &'a ValueNoObj)> {
+        let mut diffs = Vec::new();
+        let mut stack = Vec::new();
+        stack.push((String::new(), self, other));
+        while let Some((path, a, b)) = stack.pop() {
+            match (a, b) {
+                (ValueNoObj::Array(x), ValueNoObj::Array(y)) if x.len() == y.len() => {
+                    // Pushed in reverse so the first element is popped first.
+                    for (i, (x, y)) in x.iter().zip(y).enumerate().rev() {
+                        stack.push((child_path(&path, i), x, y));
+                    }
+                }
+                _ if a == b => {}
+                _ => diffs.push((path, a, b)),
+            }
+        }
+        diffs
+    }
+
+    /// Combines two arrays of the same length element by element, collecting
+    /// `f`'s results into a new array.
+    ///
+    /// Returns `None` if either value is not an array or their lengths
+    /// differ. Only the top level is zipped; nested arrays are handed to `f`
+    /// whole.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// let add = |a: &ValueNoObj, b: &ValueNoObj| match (a.as_f64(), b.as_f64()) {
+    ///     (Some(a), Some(b)) => ValueNoObj::from(a + b),
+    ///     _ => ValueNoObj::Null,
+    /// };
+    ///
+    /// let a = value_no_obj!([1, 2.5, "x"]);
+    /// let b = value_no_obj!([10, 0.5, 3]);
+    /// assert_eq!(a.zip_with(&b, add), Some(value_no_obj!([11.0, 3.0, null])));
+    ///
+    /// assert_eq!(a.zip_with(&value_no_obj!([1, 2]), add), None);
+    /// assert_eq!(a.zip_with(&ValueNoObj::from(1), add), None);
+    /// assert_eq!(value_no_obj!([]).zip_with(&value_no_obj!([]), add), Some(value_no_obj!([])));
+    ///
+    /// let pairs = value_no_obj!([1, 2]).zip_with(&value_no_obj!(["a", "b"]), |a, b| {
+    ///     value_no_obj!([a.clone(), b.clone()])
+    /// });
+    /// assert_eq!(pairs, Some(value_no_obj!([[1, "a"], [2, "b"]])));
+    /// ```
+    pub fn zip_with<F>(&self, other: &ValueNoObj, mut f: F) -> Option<ValueNoObj>
+    where
+        F: FnMut(&ValueNoObj, &ValueNoObj) -> ValueNoObj,
+    {
+        match (self, other) {
+            (ValueNoObj::Array(a), ValueNoObj::Array(b)) if a.len() == b.len() => Some(
+                ValueNoObj::Array(a.iter().zip(b).map(|(a, b)| f(a, b)).collect()),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Compares two values like `==`, except that numbers match when they are
+    /// within `epsilon` of each other.
+    ///
+    /// Arrays must have the same length and match element by element, at any
+    /// depth. Numbers are compared as `f64`, so integers that differ only
+    /// beyond `f64` precision also match. Everything else must be equal.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// let sum = value_no_obj!([0.1 + 0.2, ["x", [1.0]]]);
+    /// let expected = value_no_obj!([0.3, ["x", [1]]]);
+    /// assert_ne!(sum, expected);
+    /// assert!(sum.approx_eq(&expected, 1e-9));
+    ///
+    /// assert!(!value_no_obj!([0.3]).approx_eq(&value_no_obj!([0.31]), 1e-9));
+    /// assert!(value_no_obj!([0.3]).approx_eq(&value_no_obj!([0.31]), 0.05));
+    ///
+    /// // Structure still has to match exactly.
+    /// assert!(!value_no_obj!([1]).approx_eq(&value_no_obj!([1, 1]), 1.0));
+    /// assert!(!value_no_obj!([1]).approx_eq(&value_no_obj!(["1"]), 1.0));
+    /// assert!(!value_no_obj!([null]).approx_eq(&value_no_obj!([0]), 1.0));
+    /// assert!(ValueNoObj::from("a").approx_eq(&ValueNoObj::from("a"), 0.0));
+    /// ```
+    pub fn approx_eq(&self, other: &ValueNoObj, epsilon: f64) -> bool {
+        let mut stack = Vec::new();
+        stack.push((self, other));
+        while let Some((a, b)) = stack.pop() {
+            match (a, b) {
+                (ValueNoObj::Array(x), ValueNoObj::Array(y)) => {
+                    if x.len() != y.len() {
+                        return false;
+                    }
+                    stack.extend(x.iter().zip(y));
+                }
+                (ValueNoObj::Number(x), ValueNoObj::Number(y)) => {
+                    let close = match (x.as_f64(), y.as_f64()) {
+                        (Some(x), Some(y)) => (x - y).abs() <= epsilon,
+                        _ => x == y,
+                    };
+                    if !close {
+                        return false;
+                    }
+                }
+                _ if a == b => {}
+                _ => return false,
+            }
+        }
+        true
+    }
+
+    /// Appends the value's compact JSON text to any [`fmt::Write`] sink,
+    /// such as a `String`.
+    ///
+    /// This is what `{}` formatting writes. It does not go through
+    /// `io::Write`, so it is available with only the `alloc` feature, and
+    /// nested arrays are walked without recursion.
+    ///
+    /// ```
+    /// # use serde_json_extensions::value_no_obj;
+    /// #
+    /// let value = value_no_obj!([1, ["a\"b", [null, 2.5]], true]);
+    ///
+    /// let mut buf = String::with_capacity(64);
+    /// buf.push_str("value=");
+    /// value.write_compact(&mut buf).unwrap();
+    /// assert_eq!(buf, r#"value=[1,["a\"b",[null,2.5]],true]"#);
+    /// assert_eq!(buf.capacity(), 64);
+    /// assert_eq!(&buf["value=".len()..], value.to_string());
+    ///
+    /// // Errors from the sink are passed through.
+    /// struct Full;
+    /// impl std::fmt::Write for Full {
+    ///     fn write_str(&mut self, _: &str) -> std::fmt::Result {
+    ///         Err(std::fmt::Error)
+    ///     }
+    /// }
+    /// assert!(value.write_compact(&mut Full).is_err());
+    /// ```
+    pub fn write_compact<W>(&self, w: &mut W) -> fmt::Result
+    where
+        W: ?Sized + fmt::Write,
+    {
+        self.write_json(&mut FmtWriter { inner: w }, &mut CompactFormatter)
+            .map_err(|_| fmt::Error)
+    }
+
+    /// Computes a 64-bit FNV-1a hash of the value's compact JSON text, the
+    /// same text [`to_vec`] produces.
+    ///
+    /// Unlike [`Hash`](core::hash::Hash) with `std`'s randomly seeded
+    /// hashers, the result is the same in every process and on every
+    /// platform, so it can be stored as a cache key. Values that serialize to
+    /// the same text hash the same, however their arrays were built or
+    /// however much spare capacity they have.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// let parsed: ValueNoObj = r#"[1, ["a", null], 2.5, true]"#.parse().unwrap();
+    ///
+    /// let mut inner = ValueNoObj::with_capacity(100);
+    /// inner.push("a");
+    /// inner.push(ValueNoObj::Null);
+    /// let mut built = ValueNoObj::with_capacity(1);
+    /// built.push(1);
+    /// built.push(inner);
+    /// built.push(2.5);
+    /// built.push(true);
+    ///
+    /// assert_eq!(parsed, built);
+    /// assert_eq!(parsed.content_hash(), built.content_hash());
+    ///
+    /// // Stable across releases: the FNV-1a offset basis hashes no bytes,
+    /// // and `null` is always hashed as its four characters.
+    /// assert_eq!(ValueNoObj::Null.content_hash(), {
+    ///     let mut h: u64 = 0xcbf2_9ce4_8422_2325;
+    ///     for b in b"null" {
+    ///         h = (h ^ u64::from(*b)).wrapping_mul(0x100_0000_01b3);
+    ///     }
+    ///     h
+    /// });
+    ///
+    /// assert_ne!(parsed.content_hash(), value_no_obj!([1, ["a"], 2.5, true]).content_hash());
+    /// assert_ne!(value_no_obj!([1]).content_hash(), value_no_obj!([1.0]).content_hash());
+    /// assert_ne!(value_no_obj!(["1"]).content_hash(), value_no_obj!([1]).content_hash());
+    /// ```
+    pub fn content_hash(&self) -> u64 {
+        struct Fnv1a(u64);
+
+        impl io::Write for Fnv1a {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                for &byte in buf {
+                    self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x100_0000_01b3);
+                }
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut hasher = Fnv1a(0xcbf2_9ce4_8422_2325);
+        // Writing to the hasher cannot fail.
+        let _ = self.write_json(&mut hasher, &mut CompactFormatter);
+        hasher.0
+    }
+
+    /// Builds an array of arrays, one inner array per row.
+    ///
+    /// Rows may have different lengths; nothing pads or checks them.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// let rows = vec![
+    ///     vec![ValueNoObj::from(1), ValueNoObj::from(2), ValueNoObj::from(3)],
+    ///     vec![ValueNoObj::from(4), ValueNoObj::from(5), ValueNoObj::from(6)],
+    /// ];
+    /// let matrix = ValueNoObj::from_rows(rows);
+    ///
+    /// assert_eq!(matrix, value_no_obj!([[1, 2, 3], [4, 5, 6]]));
+    /// assert_eq!(matrix[1][2], 6);
+    /// ```
+    pub fn from_rows<I, R>(rows: I) -> ValueNoObj
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = ValueNoObj>,
+    {
+        ValueNoObj::Array(
+            rows.into_iter()
+                .map(|row| ValueNoObj::Array(row.into_iter().collect()))
+                .collect(),
+        )
+    }
+
+    /// Creates an empty `ValueNoObj::Array` with room for at least `capacity`
+    /// elements.
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObj;
+    /// #
+    /// let v = ValueNoObj::with_capacity(8);
+    /// assert_eq!(v, ValueNoObj::Array(Vec::new()));
+    /// assert!(v.as_array().unwrap().capacity() >= 8);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> ValueNoObj {
+        ValueNoObj::Array(Vec::with_capacity(capacity))
+    }
+
+    /// Appends an element to the back of a `ValueNoObj::Array`.
+    ///
+    /// If the value is not an array it is first turned into one: `Null`
+    /// becomes an empty array, and any other scalar becomes a single-element
+    /// array holding the old value. Pushing therefore never panics.
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObj;
+    /// #
+    /// let mut v = ValueNoObj::with_capacity(2);
+    /// v.push(1);
+    /// v.push("two");
+    /// assert_eq!(v, ValueNoObj::from(vec![ValueNoObj::from(1), ValueNoObj::from("two")]));
+    ///
+    /// let mut null = ValueNoObj::Null;
+    /// null.push(true);
+    /// assert_eq!(null, ValueNoObj::from(vec![true]));
+    ///
+    /// let mut scalar = ValueNoObj::from(1);
+    /// scalar.push(2);
+    /// assert_eq!(scalar, ValueNoObj::from(vec![1, 2]));
+    /// ```
+    pub fn push<T: Into<ValueNoObj>>(&mut self, value: T) {
+        self.make_array().push(value.into());
+    }
+
+    /// Moves the elements of `other` onto the back of a `ValueNoObj::Array`.
+    ///
+    /// `self` is first turned into an array as [`push`](ValueNoObj::push)
+    /// describes, so `Null` starts out empty and a scalar becomes the first
+    /// element. If `other` is not an array it is pushed as a single element,
+    /// `Null` included. Nested arrays in `other` are appended whole.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// #
+    /// let mut all = ValueNoObj::Null;
+    /// for chunk in [value_no_obj!([1, 2]), value_no_obj!([]), value_no_obj!([3, [4]])] {
+    ///     all.append(chunk);
+    /// }
+    /// assert_eq!(all, value_no_obj!([1, 2, 3, [4]]));
+    ///
+    /// all.append(ValueNoObj::from("end"));
+    /// assert_eq!(all, value_no_obj!([1, 2, 3, [4], "end"]));
+    ///
+    /// let mut scalar = ValueNoObj::from(true);
+    /// scalar.append(value_no_obj!([false]));
+    /// assert_eq!(scalar, value_no_obj!([true, false]));
+    ///
+    /// let mut v = value_no_obj!([0]);
+    /// v.append(ValueNoObj::Null);
+    /// assert_eq!(v, value_no_obj!([0, null]));
+    /// ```
+    pub fn append(&mut self, other: ValueNoObj) {
+        let list = self.make_array();
+        match other {
+            ValueNoObj::Array(mut other) => list.append(&mut other),
+            scalar => list.push(scalar),
+        }
+    }
+
+    /// Turns `self` into an array the way [`push`](ValueNoObj::push)
+    /// documents, and returns the elements.
+    pub(crate) fn make_array(&mut self) -> &mut Vec<ValueNoObj> {
+        if !self.is_array() {
+            let mut list = Vec::new();
+            match self.take() {
+                ValueNoObj::Null => {}
+                scalar => list.push(scalar),
+            }
+            *self = ValueNoObj::Array(list);
+        }
+        match self {
+            ValueNoObj::Array(list) => list,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// The default value is `ValueNoObj::Null`.
+///
+/// This is useful for handling omitted `ValueNoObj` fields when deserializing,
+/// for `#[derive(Default)]` structs, and for [`mem::take`].
+///
+/// # Examples
+///
+/// ```
+/// # use serde::Deserialize;
+/// use serde_json_extensions::ValueNoObj;
+///
+/// #[derive(Deserialize, Default)]
+/// struct Settings {
+///     level: i32,
+///     #[serde(default)]
+///     extras: ValueNoObj,
+/// }
+///
+/// # fn try_main() -> Result<(), serde_json_extensions::Error> {
+/// let data = r#" { "level": 42 } "#;
+/// let s: Settings = serde_json_extensions::from_str(data)?;
+///
+/// assert_eq!(s.level, 42);
+/// assert_eq!(s.extras, ValueNoObj::Null);
+/// assert_eq!(Settings::default().extras, ValueNoObj::default());
+///
+/// let mut v = ValueNoObj::from("taken");
+/// assert_eq!(std::mem::take(&mut v), "taken");
+/// assert_eq!(v, ValueNoObj::Null);
+/// #
+/// #     Ok(())
+/// # }
+/// #
+/// # try_main().unwrap()
+/// ```
+impl Default for ValueNoObj {
+    fn default() -> ValueNoObj {
         ValueNoObj::Null
     }
 }
@@ -871,8 +2459,16 @@ impl Default for ValueNoObj {
 mod de;
 mod from;
 mod index;
+mod iter;
+mod parse;
 mod partial_eq;
+mod patch;
+#[cfg(feature = "raw_value")]
+mod raw_numbers;
 mod ser;
+mod shared;
+mod tokens;
+mod write;
 
 /// Convert a `T` into `serde_json::Value` which is an enum that can represent
 /// any valid JSON data.
@@ -977,3 +2573,89 @@ where
 {
     T::deserialize(value)
 }
+
+/// Serialize a `ValueNoObj` as JSON into the I/O stream.
+///
+/// The output is the same as [`to_writer`](crate::to_writer) produces, but
+/// nested arrays are walked without recursion, so any value that could be
+/// built can be written.
+///
+/// ```
+/// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+/// #
+/// let value = value_no_obj!([1, ["a", [null, 2.5]], true]);
+///
+/// let mut buf = Vec::new();
+/// serde_json_extensions::value_no_obj::to_writer(&mut buf, &value).unwrap();
+/// assert_eq!(buf, br#"[1,["a",[null,2.5]],true]"#);
+/// assert_eq!(ValueNoObj::from_slice(&buf).unwrap(), value);
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn to_writer<W>(mut writer: W, value: &ValueNoObj) -> Result<(), Error>
+where
+    W: io::Write,
+{
+    value
+        .write_json(&mut writer, &mut CompactFormatter)
+        .map_err(Error::io)
+}
+
+/// Serialize a `ValueNoObj` as pretty-printed JSON into the I/O stream.
+///
+/// ```
+/// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+/// #
+/// let value = value_no_obj!([1, [2]]);
+///
+/// let mut buf = Vec::new();
+/// serde_json_extensions::value_no_obj::to_writer_pretty(&mut buf, &value).unwrap();
+/// assert_eq!(buf, b"[\n  1,\n  [\n    2\n  ]\n]");
+/// assert_eq!(ValueNoObj::from_slice(&buf).unwrap(), value);
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn to_writer_pretty<W>(mut writer: W, value: &ValueNoObj) -> Result<(), Error>
+where
+    W: io::Write,
+{
+    value
+        .write_json(&mut writer, &mut PrettyFormatter::new())
+        .map_err(Error::io)
+}
+
+/// Serialize a `ValueNoObj` as a JSON byte vector.
+///
+/// ```
+/// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+/// #
+/// let value = value_no_obj!([[], ["x"]]);
+/// let buf = serde_json_extensions::value_no_obj::to_vec(&value).unwrap();
+/// assert_eq!(buf, br#"[[],["x"]]"#);
+/// assert_eq!(ValueNoObj::from_slice(&buf).unwrap(), value);
+/// ```
+pub fn to_vec(value: &ValueNoObj) -> Result<Vec<u8>, Error> {
+    let mut writer = Vec::with_capacity(128);
+    tri!(value
+        .write_json(&mut writer, &mut CompactFormatter)
+        .map_err(Error::io));
+    Ok(writer)
+}
+
+/// Serialize a `ValueNoObj` as a pretty-printed JSON byte vector.
+///
+/// ```
+/// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+/// #
+/// let value = value_no_obj!([[], ["x"]]);
+/// let buf = serde_json_extensions::value_no_obj::to_vec_pretty(&value).unwrap();
+/// assert_eq!(buf, b"[\n  [],\n  [\n    \"x\"\n  ]\n]");
+/// assert_eq!(ValueNoObj::from_slice(&buf).unwrap(), value);
+/// ```
+pub fn to_vec_pretty(value: &ValueNoObj) -> Result<Vec<u8>, Error> {
+    let mut writer = Vec::with_capacity(128);
+    tri!(value
+        .write_json(&mut writer, &mut PrettyFormatter::new())
+        .map_err(Error::io));
+    Ok(writer)
+}
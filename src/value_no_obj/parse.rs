@@ -0,0 +1,170 @@
+use super::ValueNoObj;
+use crate::de::Deserializer;
+use crate::error::Error;
+use serde::de::Deserialize;
+
+/// What the tokens `NaN`, `Infinity` and `-Infinity` become when
+/// [`ParseOptions::accept_nan`] lets them through.
+///
+/// A `Number` cannot hold a non-finite float, so each token has to be mapped
+/// onto some other variant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NonFinite {
+    /// `Null`, the way `serde_json` serializes a non-finite float.
+    Null,
+
+    /// A `String` holding the token exactly as spelled, e.g. `"-Infinity"`.
+    String,
+}
+
+/// Settings for [`ValueNoObj::parse_with`], gathering the ways parsing can be
+/// made stricter or more lenient in one place.
+///
+/// [`ParseOptions::default()`] parses exactly what [`FromStr`] and
+/// [`from_str`](crate::from_str) do. Override individual fields with struct
+/// update syntax.
+///
+/// # Limits on nested arrays
+///
+/// `max_depth` is a budget that every open array spends one unit of, the
+/// outermost included, and the array that spends the last unit fails. So at
+/// most `max_depth - 1` arrays may be nested inside one another: the default
+/// of 128 allows 127, as in `serde_json`, and 0 or 1 rejects every array.
+/// `max_elements` counts elements of all arrays together, including the
+/// nested arrays themselves.
+///
+/// ```
+/// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+/// use serde_json_extensions::value_no_obj::ParseOptions;
+///
+/// let input = "[[1, [2]], [[3], 4]]";
+///
+/// let depth = |max_depth| ParseOptions { max_depth, ..ParseOptions::default() };
+/// assert!(ValueNoObj::parse_with(input, &depth(4)).is_ok());
+/// let err = ValueNoObj::parse_with(input, &depth(3)).unwrap_err();
+/// assert_eq!(err.to_string(), "recursion limit exceeded at line 1 column 6");
+///
+/// assert!(ValueNoObj::parse_with("[1]", &depth(2)).is_ok());
+/// assert!(ValueNoObj::parse_with("[1]", &depth(1)).is_err());
+/// assert!(ValueNoObj::parse_with("[1]", &depth(0)).is_err());
+/// assert!(ValueNoObj::parse_with("1", &depth(0)).is_ok());
+///
+/// // Two outer elements, four in the middle and two innermost.
+/// let elements = |max| ParseOptions { max_elements: Some(max), ..ParseOptions::default() };
+/// let v = ValueNoObj::parse_with(input, &elements(8)).unwrap();
+/// assert_eq!(v, value_no_obj!([[1, [2]], [[3], 4]]));
+/// let err = ValueNoObj::parse_with(input, &elements(7)).unwrap_err();
+/// assert!(err.is_data());
+///
+/// // Empty arrays hold no elements.
+/// assert!(ValueNoObj::parse_with("[[], [[]]]", &elements(3)).is_ok());
+/// assert!(ValueNoObj::parse_with("[]", &elements(0)).is_ok());
+/// assert!(ValueNoObj::parse_with("[[]]", &elements(0)).is_err());
+/// ```
+///
+/// [`FromStr`]: core::str::FromStr
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+    /// One more than the number of arrays that may be nested inside one
+    /// another; opening array number `max_depth` fails with a "recursion
+    /// limit exceeded" error, and 0 behaves like 1. Defaults to 128.
+    pub max_depth: u8,
+
+    /// The most array elements the whole input may hold, counted across all
+    /// arrays, or `None` for no limit. Defaults to `None`.
+    pub max_elements: Option<usize>,
+
+    /// What accepted non-finite tokens turn into. Only consulted when
+    /// `accept_nan` is set. Defaults to [`NonFinite::Null`].
+    pub on_nonfinite: NonFinite,
+
+    /// Whether the non-standard tokens `NaN`, `Infinity` and `-Infinity` are
+    /// accepted wherever a value is expected. Defaults to `false`.
+    pub accept_nan: bool,
+
+    /// Whether anything other than whitespace after the value is an error.
+    /// When `false`, parsing stops at the end of the first value and ignores
+    /// the rest of the input. Defaults to `true`.
+    pub reject_trailing: bool,
+
+    /// Whether a number written with a fraction or an exponent, such as
+    /// `1.0` or `1e3`, is an error, at any depth. Defaults to `false`.
+    pub integers_only: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            max_depth: 128,
+            max_elements: None,
+            on_nonfinite: NonFinite::Null,
+            accept_nan: false,
+            reject_trailing: true,
+            integers_only: false,
+        }
+    }
+}
+
+impl ValueNoObj {
+    /// Parses a `ValueNoObj` under the given [`ParseOptions`].
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// use serde_json_extensions::value_no_obj::{NonFinite, ParseOptions};
+    ///
+    /// // The defaults agree with `FromStr`.
+    /// let input = "[1, [2.5, \"x\"], null]";
+    /// assert_eq!(
+    ///     ValueNoObj::parse_with(input, &ParseOptions::default()).unwrap(),
+    ///     input.parse::<ValueNoObj>().unwrap(),
+    /// );
+    ///
+    /// let options = ParseOptions {
+    ///     max_depth: 3,
+    ///     max_elements: Some(4),
+    ///     accept_nan: true,
+    ///     on_nonfinite: NonFinite::String,
+    ///     reject_trailing: false,
+    ///     integers_only: false,
+    /// };
+    /// let v = ValueNoObj::parse_with("[[NaN, 1], -Infinity] trailing", &options).unwrap();
+    /// assert_eq!(v, value_no_obj!([["NaN", 1], "-Infinity"]));
+    ///
+    /// let err = ValueNoObj::parse_with("[[[1]]]", &options).unwrap_err();
+    /// assert_eq!(err.to_string(), "recursion limit exceeded at line 1 column 3");
+    ///
+    /// let err = ValueNoObj::parse_with("[[1, 2], [3, 4]]", &options).unwrap_err();
+    /// assert!(err.is_data());
+    /// assert_eq!(err.to_string(), "too many array elements at line 1 column 11");
+    ///
+    /// // Objects are rejected whatever the options.
+    /// assert!(ValueNoObj::parse_with("[{}]", &options).is_err());
+    ///
+    /// let options = ParseOptions { accept_nan: true, ..ParseOptions::default() };
+    /// let v = ValueNoObj::parse_with("[Infinity, 0]", &options).unwrap();
+    /// assert_eq!(v, value_no_obj!([null, 0]));
+    /// assert!(ValueNoObj::parse_with("[Infinity, 0] 1", &options).is_err());
+    ///
+    /// let options = ParseOptions { integers_only: true, ..ParseOptions::default() };
+    /// let v = ValueNoObj::parse_with("[1, [-2, [3]], \"4.5\"]", &options).unwrap();
+    /// assert_eq!(v, value_no_obj!([1, [-2, [3]], "4.5"]));
+    /// let err = ValueNoObj::parse_with("[1, [2, [3e0]]]", &options).unwrap_err();
+    /// assert_eq!(err.to_string(), "floating point numbers are not allowed at line 1 column 11");
+    /// assert!(ValueNoObj::parse_with("[1.5]", &options).unwrap_err().is_data());
+    /// ```
+    pub fn parse_with(s: &str, options: &ParseOptions) -> Result<ValueNoObj, Error> {
+        let mut de = Deserializer::from_str(s);
+        de.set_max_depth(options.max_depth);
+        if let Some(max_elements) = options.max_elements {
+            de.set_max_elements(max_elements);
+        }
+        de.set_accept_non_finite(options.accept_nan);
+        de.set_non_finite_as_str(options.on_nonfinite == NonFinite::String);
+        de.set_integers_only(options.integers_only);
+        let value = tri!(ValueNoObj::deserialize(&mut de));
+        if options.reject_trailing {
+            tri!(de.end());
+        }
+        Ok(value)
+    }
+}
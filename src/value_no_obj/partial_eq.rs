@@ -1,5 +1,34 @@
+//! `ValueNoObj` compares directly with strings, booleans and the numeric
+//! primitives, in either order, and element-wise with slices and `Vec`s of
+//! anything it compares with. A comparison only succeeds when the variant
+//! matches: a number never equals a string, and an array never equals a
+//! scalar.
+//!
+//! ```
+//! # use serde_json_extensions::{value_no_obj, ValueNoObj};
+//! #
+//! let s = ValueNoObj::from("hello");
+//! assert!(s == "hello" && "hello" == s);
+//! assert!(s == String::from("hello") && String::from("hello") == s);
+//!
+//! let n = ValueNoObj::from(42);
+//! assert!(n == 42i8 && n == 42i16 && n == 42i32 && n == 42i64 && n == 42isize);
+//! assert!(n == 42u8 && n == 42u16 && n == 42u32 && n == 42u64 && n == 42usize);
+//! assert!(n == 42.0f64 && n == 42.0f32 && 42 == n);
+//! assert!(ValueNoObj::from(true) == true);
+//! assert!(ValueNoObj::from("42") != 42);
+//!
+//! let array = value_no_obj!([1, 2, 3]);
+//! assert!(array == vec![1, 2, 3]);
+//! assert!(vec![1, 2, 3] == array);
+//! assert!(array == [1, 2, 3][..]);
+//! assert!(array != vec![1, 2]);
+//! assert!(array != 1);
+//! ```
+
 use super::ValueNoObj;
 use alloc::string::String;
+use alloc::vec::Vec;
 
 fn eq_i64(value: &ValueNoObj, other: i64) -> bool {
     value.as_i64().map_or(false, |i| i == other)
@@ -101,3 +130,135 @@ partialeq_numeric! {
     eq_f64[f64]
     eq_bool[bool]
 }
+
+fn eq_slice<T>(value: &ValueNoObj, other: &[T]) -> bool
+where
+    ValueNoObj: PartialEq<T>,
+{
+    match value {
+        ValueNoObj::Array(list) => {
+            list.len() == other.len() && list.iter().zip(other).all(|(a, b)| a == b)
+        }
+        _ => false,
+    }
+}
+
+/// Compares element-wise against a slice of anything a `ValueNoObj` can be
+/// compared with.
+///
+/// Only an `Array` of the same length whose every element compares equal
+/// matches. Elements use the scalar comparisons above, so an integer slice
+/// never matches an array holding floats or strings.
+///
+/// ```
+/// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+/// #
+/// let v = value_no_obj!([1, 2, 3]);
+/// assert!(v == [1, 2, 3][..]);
+/// assert!(v == vec![1, 2, 3]);
+/// assert!(vec![1, 2, 3] == v);
+///
+/// assert!(v != [1, 2][..]);
+/// assert!(v != [1, 2, 4][..]);
+/// assert!(v != ["1", "2", "3"][..]);
+/// assert!(value_no_obj!([1.0, 2.0, 3.0]) != [1, 2, 3][..]);
+/// assert!(value_no_obj!(["a", "b"]) == ["a", "b"][..]);
+/// assert!(value_no_obj!(["a", 1]) != ["a", "1"][..]);
+/// assert!(ValueNoObj::Null != [0i64; 0][..]);
+///
+/// let nested = value_no_obj!([[1, 2], [3]]);
+/// assert!(nested == vec![vec![1, 2], vec![3]]);
+/// ```
+impl<T> PartialEq<[T]> for ValueNoObj
+where
+    ValueNoObj: PartialEq<T>,
+{
+    fn eq(&self, other: &[T]) -> bool {
+        eq_slice(self, other)
+    }
+}
+
+impl<T> PartialEq<ValueNoObj> for [T]
+where
+    ValueNoObj: PartialEq<T>,
+{
+    fn eq(&self, other: &ValueNoObj) -> bool {
+        eq_slice(other, self)
+    }
+}
+
+impl<T> PartialEq<Vec<T>> for ValueNoObj
+where
+    ValueNoObj: PartialEq<T>,
+{
+    fn eq(&self, other: &Vec<T>) -> bool {
+        eq_slice(self, other)
+    }
+}
+
+impl<T> PartialEq<ValueNoObj> for Vec<T>
+where
+    ValueNoObj: PartialEq<T>,
+{
+    fn eq(&self, other: &ValueNoObj) -> bool {
+        eq_slice(other, self)
+    }
+}
+
+#[cfg(feature = "serde_json")]
+fn eq_json(value: &ValueNoObj, other: &serde_json::Value) -> bool {
+    match (value, other) {
+        (ValueNoObj::Null, serde_json::Value::Null) => true,
+        (ValueNoObj::Bool(a), serde_json::Value::Bool(b)) => a == b,
+        (ValueNoObj::Number(a), serde_json::Value::Number(b)) => {
+            if let Some(a) = a.as_u64() {
+                b.as_u64() == Some(a)
+            } else if let Some(a) = a.as_i64() {
+                b.as_i64() == Some(a)
+            } else {
+                b.is_f64() && a.as_f64() == b.as_f64()
+            }
+        }
+        (ValueNoObj::String(a), serde_json::Value::String(b)) => a == b,
+        (ValueNoObj::Array(a), serde_json::Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| eq_json(a, b))
+        }
+        _ => false,
+    }
+}
+
+/// Compares structurally against a `serde_json::Value`.
+///
+/// Integers and floats never compare equal to each other, matching
+/// `serde_json`, and a `serde_json::Value::Object` is never equal to any
+/// `ValueNoObj`.
+///
+/// *This impl is only available if serde_json_extensions is built with the
+/// `"serde_json"` feature.*
+///
+/// ```
+/// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+/// #
+/// assert_eq!(ValueNoObj::from("a"), serde_json::json!("a"));
+/// assert_eq!(value_no_obj!([1, [2.5, null]]), serde_json::json!([1, [2.5, null]]));
+/// assert_eq!(serde_json::json!([true]), value_no_obj!([true]));
+///
+/// assert_ne!(value_no_obj!([1]), serde_json::json!([1.0]));
+/// assert_ne!(ValueNoObj::Null, serde_json::json!({}));
+/// assert_ne!(value_no_obj!([null]), serde_json::json!([{ "a": null }]));
+/// ```
+#[cfg(feature = "serde_json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde_json")))]
+impl PartialEq<serde_json::Value> for ValueNoObj {
+    fn eq(&self, other: &serde_json::Value) -> bool {
+        eq_json(self, other)
+    }
+}
+
+#[cfg(feature = "serde_json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde_json")))]
+impl PartialEq<ValueNoObj> for serde_json::Value {
+    fn eq(&self, other: &ValueNoObj) -> bool {
+        eq_json(other, self)
+    }
+}
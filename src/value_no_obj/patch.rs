@@ -0,0 +1,139 @@
+use super::{parse_index, ValueNoObj};
+use crate::error::Error;
+use alloc::string::String;
+use serde::de::Error as _;
+
+/// A single JSON Patch ([RFC 6902](https://tools.ietf.org/html/rfc6902))
+/// operation on a `ValueNoObj`.
+///
+/// Only the operations that make sense without objects are offered, and every
+/// `path` is a JSON Pointer whose tokens are array indices. See
+/// [`ValueNoObj::apply_patch`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum PatchOp {
+    /// Inserts `value` before the element at `path`, shifting later elements
+    /// along. The last token may equal the array's length, or be `-`, to
+    /// append. An empty `path` replaces the whole value.
+    Add {
+        /// Where to insert.
+        path: String,
+        /// The element to insert.
+        value: ValueNoObj,
+    },
+
+    /// Removes the element at `path`, shifting later elements back.
+    Remove {
+        /// The element to remove.
+        path: String,
+    },
+
+    /// Replaces the existing value at `path`. An empty `path` replaces the
+    /// whole value.
+    Replace {
+        /// The value to overwrite.
+        path: String,
+        /// The new value.
+        value: ValueNoObj,
+    },
+}
+
+impl ValueNoObj {
+    /// Applies a sequence of patch operations in order.
+    ///
+    /// The patch is atomic: if any operation fails, `self` is left exactly as
+    /// it was and the error describes the first failing operation.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// use serde_json_extensions::value_no_obj::PatchOp;
+    ///
+    /// let mut v = value_no_obj!([1, [2, 3], [4]]);
+    /// v.apply_patch(&[
+    ///     PatchOp::Add { path: "/1/1".to_owned(), value: value_no_obj!("x") },
+    ///     PatchOp::Add { path: "/2/-".to_owned(), value: value_no_obj!([5]) },
+    ///     PatchOp::Remove { path: "/0".to_owned() },
+    ///     PatchOp::Replace { path: "/0/0".to_owned(), value: value_no_obj!(null) },
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(v, value_no_obj!([[null, "x", 3], [4, [5]]]));
+    ///
+    /// // Nothing is applied when any operation fails.
+    /// let err = v
+    ///     .apply_patch(&[
+    ///         PatchOp::Remove { path: "/0".to_owned() },
+    ///         PatchOp::Remove { path: "/5".to_owned() },
+    ///     ])
+    ///     .unwrap_err();
+    /// assert_eq!(err.to_string(), "path `/5` does not exist");
+    /// assert_eq!(v, value_no_obj!([[null, "x", 3], [4, [5]]]));
+    ///
+    /// let err = v
+    ///     .apply_patch(&[PatchOp::Add { path: "/0/4".to_owned(), value: value_no_obj!(0) }])
+    ///     .unwrap_err();
+    /// assert_eq!(err.to_string(), "index 4 out of range for array of length 3");
+    ///
+    /// let err = v
+    ///     .apply_patch(&[PatchOp::Replace { path: "/a".to_owned(), value: value_no_obj!(0) }])
+    ///     .unwrap_err();
+    /// assert_eq!(err.to_string(), "path `/a` does not exist");
+    /// ```
+    pub fn apply_patch(&mut self, ops: &[PatchOp]) -> Result<(), Error> {
+        let mut patched = self.clone();
+        for op in ops {
+            tri!(patched.apply_patch_op(op));
+        }
+        *self = patched;
+        Ok(())
+    }
+
+    fn apply_patch_op(&mut self, op: &PatchOp) -> Result<(), Error> {
+        match op {
+            PatchOp::Add { path, value } if path.is_empty() => {
+                *self = value.clone();
+                Ok(())
+            }
+            PatchOp::Add { path, value } => {
+                let (parent, token) = tri!(self.patch_parent(path));
+                let index = match token.as_str() {
+                    "-" => parent.as_array().map_or(0, |list| list.len()),
+                    _ => tri!(parse_index(&token).ok_or_else(|| path_not_found(path))),
+                };
+                parent.insert(index, value.clone())
+            }
+            PatchOp::Remove { path } => {
+                let (parent, token) = tri!(self.patch_parent(path));
+                match (parent, parse_index(&token)) {
+                    (ValueNoObj::Array(list), Some(index)) if index < list.len() => {
+                        list.remove(index);
+                        Ok(())
+                    }
+                    _ => Err(path_not_found(path)),
+                }
+            }
+            PatchOp::Replace { path, value } => match self.pointer_mut(path) {
+                Some(target) => {
+                    *target = value.clone();
+                    Ok(())
+                }
+                None => Err(path_not_found(path)),
+            },
+        }
+    }
+
+    /// Splits a non-empty pointer into the value holding its last token and
+    /// that token, unescaped.
+    fn patch_parent(&mut self, path: &str) -> Result<(&mut ValueNoObj, String), Error> {
+        let (parent, token) = match path.rfind('/') {
+            Some(split) => (&path[..split], &path[split + 1..]),
+            None => return Err(path_not_found(path)),
+        };
+        match self.pointer_mut(parent) {
+            Some(parent) => Ok((parent, token.replace("~1", "/").replace("~0", "~"))),
+            None => Err(path_not_found(path)),
+        }
+    }
+}
+
+fn path_not_found(path: &str) -> Error {
+    Error::custom(format_args!("path `{}` does not exist", path))
+}
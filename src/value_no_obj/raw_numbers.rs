@@ -0,0 +1,153 @@
+use super::ValueNoObj;
+use crate::de::from_str;
+use crate::error::object_not_supported;
+use crate::raw::{RawValue, ARRAY_OR_RAW_TOKEN, TOKEN};
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use serde::de::{self, Deserialize, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+
+/// A `ValueNoObj` together with the original text of every number in it.
+///
+/// Parsing into a `Number` normalizes its spelling: `1.50`, `1.5e0` and
+/// `15e-1` all become the same value. When the exact input has to be
+/// reproduced, deserialize into `WithRawNumbers` instead. The numbers are
+/// recorded in document order, i.e. the order a depth-first walk of the
+/// value visits them.
+///
+/// *This type is only available if serde_json_extensions is built with the
+/// `"raw_value"` feature.*
+///
+/// ```
+/// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+/// use serde_json_extensions::value_no_obj::WithRawNumbers;
+///
+/// let input = "[1.50, [15e-1, -0], \"2.0\", null]";
+/// let parsed: WithRawNumbers = serde_json_extensions::from_str(input).unwrap();
+///
+/// assert_eq!(parsed.raw_numbers(), ["1.50", "15e-1", "-0"]);
+/// assert_eq!(parsed.value()[2], "2.0");
+///
+/// let value: ValueNoObj = parsed.into_value();
+/// assert_eq!(value[0], 1.5);
+/// ```
+///
+/// Objects are rejected just as they are for `ValueNoObj`.
+///
+/// ```
+/// # use serde_json_extensions::value_no_obj::WithRawNumbers;
+/// #
+/// let err = serde_json_extensions::from_str::<WithRawNumbers>("[1,\n [2, {}]]").unwrap_err();
+/// assert!(err.is_data());
+/// assert_eq!(err.to_string(), "objects are not supported at line 2 column 6");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "raw_value")))]
+pub struct WithRawNumbers {
+    value: ValueNoObj,
+    raw_numbers: Vec<String>,
+}
+
+impl WithRawNumbers {
+    /// The parsed value.
+    pub fn value(&self) -> &ValueNoObj {
+        &self.value
+    }
+
+    /// The text of each number exactly as it appeared in the input, in
+    /// document order.
+    pub fn raw_numbers(&self) -> &[String] {
+        &self.raw_numbers
+    }
+
+    /// Discards the captured text and returns the parsed value.
+    pub fn into_value(self) -> ValueNoObj {
+        self.value
+    }
+}
+
+/// Deserializes one value, recording the text of every number in it.
+///
+/// Arrays are walked element by element through this crate's deserializer,
+/// which hands back each scalar as its raw text, so the input is read once
+/// however deeply the arrays nest, and errors point into the whole input.
+struct Capture<'a> {
+    raw_numbers: &'a mut Vec<String>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for Capture<'a> {
+    type Value = ValueNoObj;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<ValueNoObj, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(ARRAY_OR_RAW_TOKEN, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for Capture<'a> {
+    type Value = ValueNoObj;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("any valid JSON value other than an object")
+    }
+
+    fn visit_seq<V>(self, mut visitor: V) -> Result<ValueNoObj, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut list = Vec::new();
+        while let Some(element) = tri!(visitor.next_element_seed(Capture {
+            raw_numbers: &mut *self.raw_numbers,
+        })) {
+            list.push(element);
+        }
+        Ok(ValueNoObj::Array(list))
+    }
+
+    /// A scalar, as the single-entry map `RawValue` is deserialized from.
+    fn visit_map<V>(self, mut visitor: V) -> Result<ValueNoObj, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        match tri!(visitor.next_key::<String>()) {
+            Some(key) if key == TOKEN => {}
+            _ => return Err(object_not_supported()),
+        }
+        let json: String = tri!(visitor.next_value());
+        let value: ValueNoObj = tri!(from_str(&json).map_err(de::Error::custom));
+        if value.is_number() {
+            self.raw_numbers.push(json);
+        }
+        Ok(value)
+    }
+
+    /// Some other deserializer, which does not know the token. Buffer its
+    /// value as raw JSON and walk that instead.
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<ValueNoObj, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = tri!(Box::<RawValue>::deserialize(deserializer));
+        let mut de = crate::Deserializer::from_str(raw.get());
+        let value = tri!(self.deserialize(&mut de).map_err(de::Error::custom));
+        tri!(de.end().map_err(de::Error::custom));
+        Ok(value)
+    }
+}
+
+impl<'de> Deserialize<'de> for WithRawNumbers {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut raw_numbers = Vec::new();
+        let value = tri!(Capture {
+            raw_numbers: &mut raw_numbers,
+        }
+        .deserialize(deserializer));
+        Ok(WithRawNumbers { value, raw_numbers })
+    }
+}
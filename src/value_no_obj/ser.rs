@@ -1,4 +1,8 @@
-use crate::error::{Error, ErrorCode, Result};
+use crate::error::{object_not_supported, Error, ErrorCode, Result};
+use crate::io;
+#[cfg(not(feature = "arbitrary_precision"))]
+use crate::number::N;
+use crate::ser::{format_escaped_str, Formatter};
 use crate::value_no_obj::Map;
 use crate::value_no_obj::{to_value, ValueNoObj};
 use alloc::borrow::ToOwned;
@@ -6,10 +10,17 @@ use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use core::fmt::Display;
 use core::result;
-use serde::de;
-use serde::de::Unexpected;
+use core::slice;
 use serde::ser::{Impossible, Serialize};
 
+/// Serializes the value through any serde `Serializer`.
+///
+/// Like every `Serialize` impl, this recurses once per level of array
+/// nesting, so serializing a deeply nested value (tens of thousands of
+/// levels) can overflow the stack, which aborts the process. To write JSON
+/// text, use [`value_no_obj::to_vec`](crate::value_no_obj::to_vec) or
+/// [`value_no_obj::to_writer`](crate::value_no_obj::to_writer) instead; they
+/// produce the same output without recursing.
 impl Serialize for ValueNoObj {
     #[inline]
     fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
@@ -26,6 +37,86 @@ impl Serialize for ValueNoObj {
     }
 }
 
+impl ValueNoObj {
+    /// Writes this value as JSON text without recursing into nested arrays.
+    ///
+    /// The generic `Serialize` impl above has to recurse once per level of
+    /// nesting, because serde hands each element to the serializer by
+    /// reference. When the output is JSON text, an explicit stack of array
+    /// iterators does the same job, so arbitrarily deep values cannot
+    /// overflow the call stack. The output is byte-identical to serializing
+    /// through `crate::ser::Serializer` with the same formatter.
+    pub(crate) fn write_json<W, F>(&self, writer: &mut W, formatter: &mut F) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+        F: ?Sized + Formatter,
+    {
+        // One entry per array that has been opened but not yet closed, along
+        // with whether its first element is still to come.
+        let mut stack: Vec<(slice::Iter<ValueNoObj>, bool)> = Vec::new();
+        tri!(open_json(self, writer, formatter, &mut stack));
+
+        while let Some((iter, first)) = stack.last_mut() {
+            let is_first = *first;
+            match iter.next() {
+                Some(element) => {
+                    *first = false;
+                    tri!(formatter.begin_array_value(writer, is_first));
+                    let depth = stack.len();
+                    tri!(open_json(element, writer, formatter, &mut stack));
+                    if stack.len() == depth {
+                        tri!(formatter.end_array_value(writer));
+                    }
+                }
+                None => {
+                    stack.pop();
+                    tri!(formatter.end_array(writer));
+                    if !stack.is_empty() {
+                        tri!(formatter.end_array_value(writer));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes a scalar completely, or the opening of an array, pushing the
+/// array's elements onto `stack` for `ValueNoObj::write_json` to drain.
+fn open_json<'a, W, F>(
+    value: &'a ValueNoObj,
+    writer: &mut W,
+    formatter: &mut F,
+    stack: &mut Vec<(slice::Iter<'a, ValueNoObj>, bool)>,
+) -> io::Result<()>
+where
+    W: ?Sized + io::Write,
+    F: ?Sized + Formatter,
+{
+    match value {
+        ValueNoObj::Null => formatter.write_null(writer),
+        ValueNoObj::Bool(b) => formatter.write_bool(writer, *b),
+        #[cfg(not(feature = "arbitrary_precision"))]
+        ValueNoObj::Number(n) => match n.n {
+            N::PosInt(u) => formatter.write_u64(writer, u),
+            N::NegInt(i) => formatter.write_i64(writer, i),
+            N::Float(f) => formatter.write_f64(writer, f),
+        },
+        #[cfg(feature = "arbitrary_precision")]
+        ValueNoObj::Number(n) => formatter.write_number_str(writer, &n.n),
+        ValueNoObj::String(s) => format_escaped_str(writer, formatter, s),
+        ValueNoObj::Array(list) => {
+            tri!(formatter.begin_array(writer));
+            if list.is_empty() {
+                formatter.end_array(writer)
+            } else {
+                stack.push((list.iter(), true));
+                Ok(())
+            }
+        }
+    }
+}
+
 /// Serializer whose output is a `ValueNoObj`.
 ///
 /// This is the serializer that backs [`serde_json::to_value`][crate::to_value].
@@ -206,10 +297,7 @@ impl serde::Serializer for Serializer {
     where
         T: ?Sized + Serialize,
     {
-        Err(serde::de::Error::invalid_type(
-            Unexpected::Map,
-            &"`Object` isn't supported",
-        ))
+        Err(object_not_supported())
     }
 
     #[inline]
@@ -279,12 +367,40 @@ impl serde::Serializer for Serializer {
         _variant: &'static str,
         _len: usize,
     ) -> Result<Self::SerializeStructVariant> {
-        Err(de::Error::invalid_type(
-            Unexpected::NewtypeStruct,
-            &"`Object` isn't supported",
-        ))
-    }
-
+        Err(object_not_supported())
+    }
+
+    /// Produces a `String` variant from the value's `Display` output, so
+    /// types that serialize through `collect_str`, such as date and time
+    /// types, convert without an intermediate `String` of their own.
+    ///
+    /// ```
+    /// use serde::{Serialize, Serializer};
+    /// use serde_json_extensions::value_no_obj::to_value;
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// use std::fmt;
+    ///
+    /// struct Date(u16, u8, u8);
+    ///
+    /// impl fmt::Display for Date {
+    ///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    ///         write!(f, "{:04}-{:02}-{:02}", self.0, self.1, self.2)
+    ///     }
+    /// }
+    ///
+    /// impl Serialize for Date {
+    ///     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    ///         serializer.collect_str(self)
+    ///     }
+    /// }
+    ///
+    /// let v = to_value(Date(2024, 1, 2)).unwrap();
+    /// assert_eq!(v, ValueNoObj::String("2024-01-02".to_owned()));
+    ///
+    /// // Elements go through the same path inside a sequence.
+    /// let v = to_value(vec![Date(2024, 1, 2), Date(1999, 12, 31)]).unwrap();
+    /// assert_eq!(v, value_no_obj!(["2024-01-02", "1999-12-31"]));
+    /// ```
     fn collect_str<T>(self, value: &T) -> Result<ValueNoObj>
     where
         T: ?Sized + Display,
@@ -318,11 +434,47 @@ impl serde::ser::SerializeSeq for SerializeVec {
     type Ok = ValueNoObj;
     type Error = Error;
 
+    /// An object anywhere inside an element fails with its location as a
+    /// JSON Pointer, built up one array index at a time on the way out, so a
+    /// mismatch deep inside a large value can be found directly.
+    ///
+    /// ```
+    /// # use std::collections::BTreeMap;
+    /// use serde::Serialize;
+    /// use serde_json_extensions::value_no_obj::to_value;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Order(u32, Vec<Item>);
+    ///
+    /// #[derive(Serialize)]
+    /// enum Item {
+    ///     Plain(String, u8),
+    ///     Tagged(String, BTreeMap<String, String>),
+    /// }
+    ///
+    /// let mut tags = BTreeMap::new();
+    /// tags.insert("colour".to_owned(), "red".to_owned());
+    /// let orders = vec![
+    ///     Order(1, vec![Item::Plain("a".to_owned(), 1)]),
+    ///     Order(2, vec![Item::Plain("b".to_owned(), 2), Item::Tagged("c".to_owned(), tags)]),
+    /// ];
+    ///
+    /// let err = to_value(&orders).unwrap_err();
+    /// assert!(err.is_data());
+    /// assert_eq!(err.to_string(), r#"objects are not supported at /1/1/1/1 (key "colour")"#);
+    ///
+    /// // An object at the top level has no path to report.
+    /// let err = to_value(BTreeMap::<String, u8>::new()).unwrap_err();
+    /// assert_eq!(err.to_string(), "objects are not supported");
+    /// ```
     fn serialize_element<T>(&mut self, value: &T) -> Result<()>
     where
         T: ?Sized + Serialize,
     {
-        self.vec.push(tri!(to_value(value)));
+        let index = self.vec.len();
+        self.vec.push(tri!(
+            to_value(value).map_err(|err| err.object_in_element(index))
+        ));
         Ok(())
     }
 
@@ -371,7 +523,10 @@ impl serde::ser::SerializeTupleVariant for SerializeTupleVariant {
     where
         T: ?Sized + Serialize,
     {
-        self.vec.push(tri!(to_value(value)));
+        let index = self.vec.len();
+        self.vec.push(tri!(
+            to_value(value).map_err(|err| err.object_in_element(index))
+        ));
         Ok(())
     }
 
@@ -400,15 +555,43 @@ impl serde::ser::SerializeMap for SerializeMap {
         }
     }
 
+    /// A `ValueNoObj` cannot hold the map, so the first value is where
+    /// serialization gives up. The error names the key that value belongs
+    /// to, which for a struct is the field name.
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// use serde::Serialize;
+    /// use serde_json_extensions::value_no_obj::to_value;
+    ///
+    /// let mut map = HashMap::new();
+    /// map.insert("colour", "red");
+    /// let err = to_value(&map).unwrap_err();
+    /// assert!(err.is_data());
+    /// assert_eq!(err.to_string(), r#"objects are not supported (key "colour")"#);
+    ///
+    /// #[derive(Serialize)]
+    /// struct Point {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let err = to_value(vec![Point { x: 1, y: 2 }]).unwrap_err();
+    /// assert_eq!(err.to_string(), r#"objects are not supported at /0 (key "x")"#);
+    ///
+    /// // An empty map has no key to report.
+    /// let err = to_value(HashMap::<String, u8>::new()).unwrap_err();
+    /// assert_eq!(err.to_string(), "objects are not supported");
+    /// ```
     fn serialize_value<T>(&mut self, _value: &T) -> Result<()>
     where
         T: ?Sized + Serialize,
     {
         match self {
-            SerializeMap::Map { .. } => Err(serde::de::Error::invalid_type(
-                Unexpected::Map,
-                &"Object aren't supported",
-            )),
+            SerializeMap::Map { next_key, .. } => Err(match next_key.take() {
+                Some(key) => Error::object_key_not_supported(key),
+                None => object_not_supported(),
+            }),
             #[cfg(feature = "arbitrary_precision")]
             SerializeMap::Number { .. } => unreachable!(),
             #[cfg(feature = "raw_value")]
@@ -418,10 +601,10 @@ impl serde::ser::SerializeMap for SerializeMap {
 
     fn end(self) -> Result<ValueNoObj> {
         match self {
-            SerializeMap::Map { .. } => Err(serde::de::Error::invalid_type(
-                Unexpected::Map,
-                &"Object aren't supported",
-            )),
+            SerializeMap::Map { next_key, .. } => Err(match next_key {
+                Some(key) => Error::object_key_not_supported(key),
+                None => object_not_supported(),
+            }),
             #[cfg(feature = "arbitrary_precision")]
             SerializeMap::Number { .. } => unreachable!(),
             #[cfg(feature = "raw_value")]
@@ -678,17 +861,11 @@ impl serde::ser::SerializeStructVariant for SerializeStructVariant {
     where
         T: ?Sized + Serialize,
     {
-        Err(serde::de::Error::invalid_type(
-            Unexpected::Map,
-            &"Object unsupported",
-        ))
+        Err(object_not_supported())
     }
 
     fn end(self) -> Result<ValueNoObj> {
-        Err(serde::de::Error::invalid_type(
-            Unexpected::Map,
-            &"Object unsupported",
-        ))
+        Err(object_not_supported())
     }
 }
 
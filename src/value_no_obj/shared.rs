@@ -0,0 +1,222 @@
+use super::{Number, ValueNoObj};
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+/// A `ValueNoObj` whose strings and arrays sit behind reference-counted
+/// pointers.
+///
+/// Cloning a `ValueNoObjShared` bumps at most one reference count, however
+/// large the tree under it, at the cost of the tree being immutable once
+/// built. Convert from a `ValueNoObj` once, then hand out clones to every
+/// reader; convert back to get an ordinary, mutable value.
+///
+/// ```
+/// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+/// use serde_json_extensions::value_no_obj::ValueNoObjShared;
+///
+/// let v = value_no_obj!([1, ["x", [null, 2.5]], [], true]);
+/// let shared = ValueNoObjShared::from(v.clone());
+///
+/// // Clones share the same array rather than copying it.
+/// let copy = shared.clone();
+/// match (&shared, &copy) {
+///     (ValueNoObjShared::Array(a), ValueNoObjShared::Array(b)) => {
+///         assert!(std::sync::Arc::ptr_eq(a, b));
+///     }
+///     _ => unreachable!(),
+/// }
+///
+/// assert_eq!(ValueNoObj::from(&copy), v);
+/// assert_eq!(ValueNoObj::from(copy), v);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ValueNoObjShared {
+    /// A JSON null value.
+    Null,
+
+    /// A JSON boolean.
+    Bool(bool),
+
+    /// A JSON number, whether integer or floating point.
+    Number(Number),
+
+    /// A JSON string.
+    String(Arc<str>),
+
+    /// A JSON array.
+    Array(Arc<[ValueNoObjShared]>),
+}
+
+impl ValueNoObjShared {
+    /// Constructs a `ValueNoObjShared::Null`.
+    ///
+    /// ```
+    /// # use serde_json_extensions::value_no_obj::ValueNoObjShared;
+    /// #
+    /// assert_eq!(ValueNoObjShared::null(), ValueNoObjShared::Null);
+    /// ```
+    pub fn null() -> ValueNoObjShared {
+        ValueNoObjShared::Null
+    }
+
+    /// Constructs a `ValueNoObjShared::Bool`.
+    ///
+    /// ```
+    /// # use serde_json_extensions::value_no_obj::ValueNoObjShared;
+    /// #
+    /// assert_eq!(ValueNoObjShared::bool(true), ValueNoObjShared::Bool(true));
+    /// ```
+    pub fn bool(b: bool) -> ValueNoObjShared {
+        ValueNoObjShared::Bool(b)
+    }
+
+    /// Constructs a `ValueNoObjShared::String` from a `&str`, a `String`, or
+    /// an existing `Arc<str>`, which is shared rather than copied.
+    ///
+    /// ```
+    /// # use serde_json_extensions::value_no_obj::ValueNoObjShared;
+    /// use std::sync::Arc;
+    ///
+    /// let s: Arc<str> = Arc::from("lorem");
+    /// assert_eq!(ValueNoObjShared::string("lorem"), ValueNoObjShared::string(s.clone()));
+    /// assert_eq!(Arc::strong_count(&s), 1);
+    /// ```
+    pub fn string<S: Into<Arc<str>>>(s: S) -> ValueNoObjShared {
+        ValueNoObjShared::String(s.into())
+    }
+
+    /// Constructs a `ValueNoObjShared::Number` from anything convertible to a
+    /// [`Number`], which covers every primitive integer type.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// use serde_json_extensions::value_no_obj::ValueNoObjShared;
+    ///
+    /// assert_eq!(ValueNoObj::from(ValueNoObjShared::number(7u8)), value_no_obj!(7));
+    /// ```
+    pub fn number<N: Into<Number>>(n: N) -> ValueNoObjShared {
+        ValueNoObjShared::Number(n.into())
+    }
+
+    /// Constructs a `ValueNoObjShared::Array` from the elements of an
+    /// iterator.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// use serde_json_extensions::value_no_obj::ValueNoObjShared;
+    ///
+    /// let v = ValueNoObjShared::array([
+    ///     ValueNoObjShared::string("a"),
+    ///     ValueNoObjShared::array([ValueNoObjShared::null(), ValueNoObjShared::bool(false)]),
+    /// ]);
+    /// assert_eq!(ValueNoObj::from(v), value_no_obj!(["a", [null, false]]));
+    /// ```
+    pub fn array<I>(elements: I) -> ValueNoObjShared
+    where
+        I: IntoIterator<Item = ValueNoObjShared>,
+    {
+        ValueNoObjShared::Array(elements.into_iter().collect())
+    }
+}
+
+impl From<ValueNoObj> for ValueNoObjShared {
+    fn from(value: ValueNoObj) -> Self {
+        rebuild(
+            value,
+            |value| match value {
+                ValueNoObj::Null => Err(ValueNoObjShared::Null),
+                ValueNoObj::Bool(b) => Err(ValueNoObjShared::Bool(b)),
+                ValueNoObj::Number(n) => Err(ValueNoObjShared::Number(n)),
+                ValueNoObj::String(s) => Err(ValueNoObjShared::String(Arc::from(s))),
+                ValueNoObj::Array(list) => Ok(list.into_iter()),
+            },
+            |list| ValueNoObjShared::Array(Arc::from(list)),
+        )
+    }
+}
+
+impl<'a> From<&'a ValueNoObj> for ValueNoObjShared {
+    fn from(value: &'a ValueNoObj) -> Self {
+        rebuild(
+            value,
+            |value| match value {
+                ValueNoObj::Null => Err(ValueNoObjShared::Null),
+                ValueNoObj::Bool(b) => Err(ValueNoObjShared::Bool(*b)),
+                ValueNoObj::Number(n) => Err(ValueNoObjShared::Number(n.clone())),
+                ValueNoObj::String(s) => Err(ValueNoObjShared::String(Arc::from(s.as_str()))),
+                ValueNoObj::Array(list) => Ok(list.iter()),
+            },
+            |list| ValueNoObjShared::Array(Arc::from(list)),
+        )
+    }
+}
+
+impl<'a> From<&'a ValueNoObjShared> for ValueNoObj {
+    fn from(value: &'a ValueNoObjShared) -> Self {
+        rebuild(
+            value,
+            |value| match value {
+                ValueNoObjShared::Null => Err(ValueNoObj::Null),
+                ValueNoObjShared::Bool(b) => Err(ValueNoObj::Bool(*b)),
+                ValueNoObjShared::Number(n) => Err(ValueNoObj::Number(n.clone())),
+                ValueNoObjShared::String(s) => Err(ValueNoObj::String(String::from(&**s))),
+                ValueNoObjShared::Array(list) => Ok(list.iter()),
+            },
+            ValueNoObj::Array,
+        )
+    }
+}
+
+impl From<ValueNoObjShared> for ValueNoObj {
+    /// The shared value may still be referenced elsewhere, so its contents
+    /// are copied out rather than moved.
+    fn from(value: ValueNoObjShared) -> Self {
+        ValueNoObj::from(&value)
+    }
+}
+
+/// Converts one tree into another without recursing, so arbitrarily deep
+/// arrays cannot overflow the stack.
+///
+/// `split` either turns a node into its converted scalar (`Err`) or hands
+/// back an iterator over its children (`Ok`); `join` assembles the converted
+/// children of one array.
+fn rebuild<S, T, I>(
+    root: S,
+    mut split: impl FnMut(S) -> Result<I, T>,
+    mut join: impl FnMut(Vec<T>) -> T,
+) -> T
+where
+    I: Iterator<Item = S>,
+{
+    let mut stack: Vec<(I, Vec<T>)> = Vec::new();
+    let mut next = root;
+    loop {
+        match split(next) {
+            Ok(children) => stack.push((children, Vec::new())),
+            Err(leaf) => match stack.last_mut() {
+                Some((_, out)) => out.push(leaf),
+                None => return leaf,
+            },
+        }
+        // Climb out of every finished array until one still has children.
+        next = loop {
+            let child = match stack.last_mut() {
+                Some((children, _)) => children.next(),
+                None => unreachable!(),
+            };
+            if let Some(child) = child {
+                break child;
+            }
+            let array = match stack.pop() {
+                Some((_, out)) => join(out),
+                None => unreachable!(),
+            };
+            match stack.last_mut() {
+                Some((_, out)) => out.push(array),
+                None => return array,
+            }
+        };
+    }
+}
@@ -0,0 +1,166 @@
+use super::{Number, ValueNoObj};
+use crate::error::{Error, ErrorCode};
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::slice;
+
+/// One token of the flat stream produced by [`ValueNoObj::tokens`].
+///
+/// Scalars borrow their contents from the value being walked. Every
+/// `ArrayStart` is matched by a later `ArrayEnd`, with the array's elements
+/// in between.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JsonToken<'a> {
+    /// A `null`.
+    Null,
+
+    /// `true` or `false`.
+    Bool(bool),
+
+    /// A number.
+    Number(&'a Number),
+
+    /// A string, without quotes or escapes.
+    String(&'a str),
+
+    /// The `[` opening an array.
+    ArrayStart,
+
+    /// The `]` closing an array.
+    ArrayEnd,
+}
+
+/// An iterator over the tokens of a `ValueNoObj`, in document order.
+///
+/// This struct is created by the [`tokens`] method on [`ValueNoObj`]. Nested
+/// arrays are walked with an explicit stack rather than by recursion.
+///
+/// [`tokens`]: ValueNoObj::tokens
+pub struct Tokens<'a> {
+    root: Option<&'a ValueNoObj>,
+    stack: Vec<slice::Iter<'a, ValueNoObj>>,
+}
+
+impl<'a> Tokens<'a> {
+    pub(super) fn new(value: &'a ValueNoObj) -> Self {
+        Tokens {
+            root: Some(value),
+            stack: Vec::new(),
+        }
+    }
+
+    fn enter(&mut self, value: &'a ValueNoObj) -> JsonToken<'a> {
+        match value {
+            ValueNoObj::Null => JsonToken::Null,
+            ValueNoObj::Bool(b) => JsonToken::Bool(*b),
+            ValueNoObj::Number(n) => JsonToken::Number(n),
+            ValueNoObj::String(s) => JsonToken::String(s),
+            ValueNoObj::Array(list) => {
+                self.stack.push(list.iter());
+                JsonToken::ArrayStart
+            }
+        }
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = JsonToken<'a>;
+
+    fn next(&mut self) -> Option<JsonToken<'a>> {
+        if let Some(root) = self.root.take() {
+            return Some(self.enter(root));
+        }
+        let next = match self.stack.last_mut() {
+            Some(iter) => iter.next(),
+            None => return None,
+        };
+        match next {
+            Some(value) => Some(self.enter(value)),
+            None => {
+                self.stack.pop();
+                Some(JsonToken::ArrayEnd)
+            }
+        }
+    }
+}
+
+impl<'a> FusedIterator for Tokens<'a> {}
+
+impl ValueNoObj {
+    /// Builds a `ValueNoObj` from a stream of [`JsonToken`]s, the inverse of
+    /// [`tokens`](ValueNoObj::tokens).
+    ///
+    /// The stream must hold exactly one value. An `ArrayEnd` with no array
+    /// open, an array left open, an empty stream, or tokens after the value
+    /// is finished are syntax errors. `JsonToken` has no object tokens, so an
+    /// object cannot be expressed at all.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+    /// use serde_json_extensions::value_no_obj::JsonToken;
+    ///
+    /// let v = value_no_obj!([1, ["x", [null, 2.5]], [], true]);
+    /// assert_eq!(ValueNoObj::from_tokens(v.tokens()).unwrap(), v);
+    ///
+    /// // A token-level transform: upper-case every string.
+    /// let v = value_no_obj!(["a", ["b", 1]]);
+    /// let upper: Vec<String> = v.tokens().filter_map(|token| match token {
+    ///     JsonToken::String(s) => Some(s.to_uppercase()),
+    ///     _ => None,
+    /// }).collect();
+    /// let mut upper = upper.iter();
+    /// let tokens = v.tokens().map(|token| match token {
+    ///     JsonToken::String(_) => JsonToken::String(upper.next().unwrap()),
+    ///     other => other,
+    /// });
+    /// assert_eq!(ValueNoObj::from_tokens(tokens).unwrap(), value_no_obj!(["A", ["B", 1]]));
+    ///
+    /// use JsonToken::{ArrayEnd, ArrayStart, Null};
+    /// let err = ValueNoObj::from_tokens([ArrayStart, Null]).unwrap_err();
+    /// assert_eq!(err.to_string(), "EOF while parsing a list");
+    /// let err = ValueNoObj::from_tokens([ArrayEnd]).unwrap_err();
+    /// assert_eq!(err.to_string(), "expected value");
+    /// let err = ValueNoObj::from_tokens([Null, Null]).unwrap_err();
+    /// assert_eq!(err.to_string(), "trailing characters");
+    /// let err = ValueNoObj::from_tokens([]).unwrap_err();
+    /// assert_eq!(err.to_string(), "EOF while parsing a value");
+    /// ```
+    pub fn from_tokens<'a, I>(tokens: I) -> Result<ValueNoObj, Error>
+    where
+        I: IntoIterator<Item = JsonToken<'a>>,
+    {
+        let mut stack: Vec<Vec<ValueNoObj>> = Vec::new();
+        let mut done = None;
+        for token in tokens {
+            if done.is_some() {
+                return Err(Error::syntax(ErrorCode::TrailingCharacters, 0, 0));
+            }
+            let value = match token {
+                JsonToken::Null => ValueNoObj::Null,
+                JsonToken::Bool(b) => ValueNoObj::Bool(b),
+                JsonToken::Number(n) => ValueNoObj::Number(n.clone()),
+                JsonToken::String(s) => ValueNoObj::String(s.to_string()),
+                JsonToken::ArrayStart => {
+                    stack.push(Vec::new());
+                    continue;
+                }
+                JsonToken::ArrayEnd => match stack.pop() {
+                    Some(list) => ValueNoObj::Array(list),
+                    None => return Err(Error::syntax(ErrorCode::ExpectedSomeValue, 0, 0)),
+                },
+            };
+            match stack.last_mut() {
+                Some(list) => list.push(value),
+                None => done = Some(value),
+            }
+        }
+        if !stack.is_empty() {
+            return Err(Error::syntax(ErrorCode::EofWhileParsingList, 0, 0));
+        }
+        match done {
+            Some(value) => Ok(value),
+            None => Err(Error::syntax(ErrorCode::EofWhileParsingValue, 0, 0)),
+        }
+    }
+}
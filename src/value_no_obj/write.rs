@@ -0,0 +1,227 @@
+use super::ValueNoObj;
+use crate::error::Error;
+use crate::io;
+use crate::ser::{CompactFormatter, Formatter, PrettyFormatter};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Settings for [`to_writer_with`], [`to_vec_with`] and [`to_string_with`],
+/// the option-taking counterparts of [`to_writer`](super::to_writer) and
+/// friends.
+///
+/// [`WriteOptions::default()`] writes exactly what
+/// [`to_vec`](super::to_vec) does. Override individual fields with struct
+/// update syntax.
+///
+/// ```
+/// # use serde_json_extensions::value_no_obj;
+/// use serde_json_extensions::value_no_obj::{to_string_with, WriteOptions};
+///
+/// let value = value_no_obj!([1, 2.5]);
+///
+/// let options = WriteOptions::default();
+/// assert_eq!(to_string_with(&value, &options).unwrap(), "[1,2.5]");
+///
+/// let options = WriteOptions { integers_as_floats: true, ..WriteOptions::default() };
+/// assert_eq!(to_string_with(&value, &options).unwrap(), "[1.0,2.5]");
+///
+/// let options = WriteOptions { pretty: true, integers_as_floats: true };
+/// assert_eq!(to_string_with(&value, &options).unwrap(), "[\n  1.0,\n  2.5\n]");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct WriteOptions {
+    /// Whether to indent the output the way
+    /// [`to_writer_pretty`](super::to_writer_pretty) does. Defaults to
+    /// `false`.
+    pub pretty: bool,
+
+    /// Whether integer numbers are written with a trailing `.0`, so that `5`
+    /// comes out as `5.0`, for consumers that expect every number to be a
+    /// float. Numbers that already have a fraction or an exponent are
+    /// untouched. Defaults to `false`.
+    pub integers_as_floats: bool,
+}
+
+/// Serialize a `ValueNoObj` as JSON into the I/O stream, under the given
+/// [`WriteOptions`].
+///
+/// ```
+/// # use serde_json_extensions::value_no_obj;
+/// use serde_json_extensions::value_no_obj::{to_writer_with, WriteOptions};
+///
+/// let value = value_no_obj!([1, [-2, 2.5], "3"]);
+/// let options = WriteOptions { integers_as_floats: true, ..WriteOptions::default() };
+///
+/// let mut buf = Vec::new();
+/// to_writer_with(&mut buf, &value, &options).unwrap();
+/// assert_eq!(buf, br#"[1.0,[-2.0,2.5],"3"]"#);
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn to_writer_with<W>(
+    mut writer: W,
+    value: &ValueNoObj,
+    options: &WriteOptions,
+) -> Result<(), Error>
+where
+    W: io::Write,
+{
+    write_with(value, &mut writer, options).map_err(Error::io)
+}
+
+/// Serialize a `ValueNoObj` as a JSON byte vector, under the given
+/// [`WriteOptions`].
+///
+/// ```
+/// # use serde_json_extensions::value_no_obj;
+/// use serde_json_extensions::value_no_obj::{to_vec_with, WriteOptions};
+///
+/// let value = value_no_obj!([1, 2.5]);
+/// let options = WriteOptions { integers_as_floats: true, ..WriteOptions::default() };
+/// assert_eq!(to_vec_with(&value, &options).unwrap(), b"[1.0,2.5]");
+/// ```
+pub fn to_vec_with(value: &ValueNoObj, options: &WriteOptions) -> Result<Vec<u8>, Error> {
+    let mut writer = Vec::with_capacity(128);
+    tri!(write_with(value, &mut writer, options).map_err(Error::io));
+    Ok(writer)
+}
+
+/// Serialize a `ValueNoObj` as a String of JSON, under the given
+/// [`WriteOptions`].
+///
+/// ```
+/// # use serde_json_extensions::{value_no_obj, ValueNoObj};
+/// use serde_json_extensions::value_no_obj::{to_string_with, WriteOptions};
+///
+/// let options = WriteOptions { integers_as_floats: true, ..WriteOptions::default() };
+///
+/// let value = value_no_obj!([1, 2.5]);
+/// let json = to_string_with(&value, &options).unwrap();
+/// assert_eq!(json, "[1.0,2.5]");
+///
+/// // Reading the output back turns the integers into floats.
+/// assert_eq!(json.parse::<ValueNoObj>().unwrap(), value_no_obj!([1.0, 2.5]));
+///
+/// assert_eq!(to_string_with(&value_no_obj!(-7), &options).unwrap(), "-7.0");
+/// assert_eq!(to_string_with(&value_no_obj!(["7"]), &options).unwrap(), r#"["7"]"#);
+/// ```
+pub fn to_string_with(value: &ValueNoObj, options: &WriteOptions) -> Result<String, Error> {
+    let vec = tri!(to_vec_with(value, options));
+    let string = unsafe {
+        // We do not emit invalid UTF-8.
+        String::from_utf8_unchecked(vec)
+    };
+    Ok(string)
+}
+
+fn write_with<W>(value: &ValueNoObj, writer: &mut W, options: &WriteOptions) -> io::Result<()>
+where
+    W: ?Sized + io::Write,
+{
+    if options.pretty {
+        value.write_json(
+            writer,
+            &mut OptionsFormatter {
+                inner: PrettyFormatter::new(),
+                integers_as_floats: options.integers_as_floats,
+            },
+        )
+    } else {
+        value.write_json(
+            writer,
+            &mut OptionsFormatter {
+                inner: CompactFormatter,
+                integers_as_floats: options.integers_as_floats,
+            },
+        )
+    }
+}
+
+/// Wraps the compact or pretty formatter, forwarding the array methods that
+/// place whitespace and adjusting how numbers are written.
+///
+/// `ValueNoObj::write_json` only ever writes `u64`, `i64` and `f64` numbers,
+/// or number strings under `arbitrary_precision`, and never objects, so those
+/// are the only other methods that need overriding.
+struct OptionsFormatter<F> {
+    inner: F,
+    integers_as_floats: bool,
+}
+
+impl<F> OptionsFormatter<F> {
+    fn float_suffix<W>(&self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        if self.integers_as_floats {
+            writer.write_all(b".0")
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<F: Formatter> Formatter for OptionsFormatter<F> {
+    fn write_i64<W>(&mut self, writer: &mut W, value: i64) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        tri!(self.inner.write_i64(writer, value));
+        self.float_suffix(writer)
+    }
+
+    fn write_u64<W>(&mut self, writer: &mut W, value: u64) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        tri!(self.inner.write_u64(writer, value));
+        self.float_suffix(writer)
+    }
+
+    fn write_f64<W>(&mut self, writer: &mut W, value: f64) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.inner.write_f64(writer, value)
+    }
+
+    fn write_number_str<W>(&mut self, writer: &mut W, value: &str) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        tri!(self.inner.write_number_str(writer, value));
+        if value.bytes().any(|b| matches!(b, b'.' | b'e' | b'E')) {
+            Ok(())
+        } else {
+            self.float_suffix(writer)
+        }
+    }
+
+    fn begin_array<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.inner.begin_array(writer)
+    }
+
+    fn end_array<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.inner.end_array(writer)
+    }
+
+    fn begin_array_value<W>(&mut self, writer: &mut W, first: bool) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.inner.begin_array_value(writer, first)
+    }
+
+    fn end_array_value<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.inner.end_array_value(writer)
+    }
+}
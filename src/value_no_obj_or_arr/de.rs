@@ -6,113 +6,253 @@ use core::fmt;
 use core::str::FromStr;
 
 use serde::de::{
-    self, Deserialize, DeserializeSeed, EnumAccess, Expected, IntoDeserializer, MapAccess,
-    Unexpected, VariantAccess, Visitor,
+    self, Deserialize, DeserializeSeed, EnumAccess, Expected, IgnoredAny, IntoDeserializer,
+    MapAccess, SeqAccess, Unexpected, VariantAccess, Visitor,
 };
 
 #[cfg(feature = "arbitrary_precision")]
 use crate::number::NumberFromString;
 
-use crate::error::Error;
+use crate::error::{object_not_supported, Error, ErrorCode};
 use crate::number::Number;
+use crate::read;
 use crate::value_no_obj_or_arr::ValueNoObjOrArr;
 
-impl<'de> Deserialize<'de> for ValueNoObjOrArr {
-    #[inline]
-    fn deserialize<D>(deserializer: D) -> Result<ValueNoObjOrArr, D::Error>
+/// Builds a `ValueNoObjOrArr` from whatever scalar the deserializer offers.
+///
+/// Maps are refused unless `coerce_objects` is set, in which case their
+/// contents are skipped and they become `Null`. The special single-entry maps
+/// carrying arbitrary-precision numbers and raw values are still decoded.
+/// Sequences are refused unless `unwrap_singleton` is set, in which case one
+/// holding exactly one scalar becomes that scalar.
+#[derive(Clone, Copy)]
+struct ValueVisitor {
+    coerce_objects: bool,
+    unwrap_singleton: bool,
+}
+
+impl<'de> DeserializeSeed<'de> for ValueVisitor {
+    type Value = ValueNoObjOrArr;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<ValueNoObjOrArr, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        struct ValueVisitor;
+        deserializer.deserialize_any(self)
+    }
+}
 
-        impl<'de> Visitor<'de> for ValueVisitor {
-            type Value = ValueNoObjOrArr;
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = ValueNoObjOrArr;
 
-            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter.write_str("any valid JSON value")
-            }
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("any valid JSON value")
+    }
 
-            #[inline]
-            fn visit_bool<E>(self, value: bool) -> Result<ValueNoObjOrArr, E> {
-                Ok(ValueNoObjOrArr::Bool(value))
-            }
+    #[inline]
+    fn visit_bool<E>(self, value: bool) -> Result<ValueNoObjOrArr, E> {
+        Ok(ValueNoObjOrArr::Bool(value))
+    }
 
-            #[inline]
-            fn visit_i64<E>(self, value: i64) -> Result<ValueNoObjOrArr, E> {
-                Ok(ValueNoObjOrArr::Number(value.into()))
-            }
+    #[inline]
+    fn visit_i64<E>(self, value: i64) -> Result<ValueNoObjOrArr, E> {
+        Ok(ValueNoObjOrArr::Number(value.into()))
+    }
 
-            #[inline]
-            fn visit_u64<E>(self, value: u64) -> Result<ValueNoObjOrArr, E> {
-                Ok(ValueNoObjOrArr::Number(value.into()))
-            }
+    #[inline]
+    fn visit_u64<E>(self, value: u64) -> Result<ValueNoObjOrArr, E> {
+        Ok(ValueNoObjOrArr::Number(value.into()))
+    }
 
-            #[inline]
-            fn visit_f64<E>(self, value: f64) -> Result<ValueNoObjOrArr, E> {
-                Ok(Number::from_f64(value).map_or(ValueNoObjOrArr::Null, ValueNoObjOrArr::Number))
-            }
+    #[inline]
+    fn visit_f64<E>(self, value: f64) -> Result<ValueNoObjOrArr, E> {
+        Ok(Number::from_f64(value).map_or(ValueNoObjOrArr::Null, ValueNoObjOrArr::Number))
+    }
 
-            #[cfg(any(feature = "std", feature = "alloc"))]
-            #[inline]
-            fn visit_str<E>(self, value: &str) -> Result<ValueNoObjOrArr, E>
-            where
-                E: serde::de::Error,
-            {
-                self.visit_string(String::from(value))
-            }
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[inline]
+    fn visit_str<E>(self, value: &str) -> Result<ValueNoObjOrArr, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_string(String::from(value))
+    }
 
-            #[cfg(any(feature = "std", feature = "alloc"))]
-            #[inline]
-            fn visit_string<E>(self, value: String) -> Result<ValueNoObjOrArr, E> {
-                Ok(ValueNoObjOrArr::String(value))
-            }
+    // The input outlives the value here, but `ValueNoObjOrArr::String`
+    // owns its contents, so the borrowed slice is copied just like in
+    // `visit_str`. Kept explicit so borrowing inputs such as
+    // `from_slice` have an obvious place to special-case.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[inline]
+    fn visit_borrowed_str<E>(self, value: &'de str) -> Result<ValueNoObjOrArr, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_str(value)
+    }
 
-            #[inline]
-            fn visit_none<E>(self) -> Result<ValueNoObjOrArr, E> {
-                Ok(ValueNoObjOrArr::Null)
-            }
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[inline]
+    fn visit_string<E>(self, value: String) -> Result<ValueNoObjOrArr, E> {
+        Ok(ValueNoObjOrArr::String(value))
+    }
 
-            #[inline]
-            fn visit_some<D>(self, deserializer: D) -> Result<ValueNoObjOrArr, D::Error>
-            where
-                D: serde::Deserializer<'de>,
-            {
-                Deserialize::deserialize(deserializer)
-            }
+    #[inline]
+    fn visit_none<E>(self) -> Result<ValueNoObjOrArr, E> {
+        Ok(ValueNoObjOrArr::Null)
+    }
 
-            #[inline]
-            fn visit_unit<E>(self) -> Result<ValueNoObjOrArr, E> {
-                Ok(ValueNoObjOrArr::Null)
-            }
+    #[inline]
+    fn visit_some<D>(self, deserializer: D) -> Result<ValueNoObjOrArr, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
 
-            #[cfg(any(feature = "std", feature = "alloc"))]
-            fn visit_map<V>(self, mut visitor: V) -> Result<ValueNoObjOrArr, V::Error>
-            where
-                V: MapAccess<'de>,
-            {
-                match tri!(visitor.next_key_seed(KeyClassifier)) {
-                    #[cfg(feature = "arbitrary_precision")]
-                    Some(KeyClass::Number) => {
-                        let number: NumberFromString = tri!(visitor.next_value());
-                        Ok(ValueNoObjOrArr::Number(number.value))
-                    }
-                    #[cfg(feature = "raw_value")]
-                    Some(KeyClass::RawValue) => {
-                        let value = tri!(visitor.next_value_seed(crate::raw::BoxedFromString));
-                        crate::from_str(value.get()).map_err(de::Error::custom)
-                    }
-                    Some(KeyClass::Map(_first_key)) => {
-                        Err(serde::de::Error::invalid_type(Unexpected::Map, &"non map"))
-                    }
-                    None => Err(serde::de::Error::invalid_type(
-                        Unexpected::Other(""),
-                        &"must provide non-array | non-object",
-                    )),
-                }
+    #[inline]
+    fn visit_unit<E>(self) -> Result<ValueNoObjOrArr, E> {
+        Ok(ValueNoObjOrArr::Null)
+    }
+
+    fn visit_seq<V>(self, mut visitor: V) -> Result<ValueNoObjOrArr, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        if !self.unwrap_singleton {
+            return Err(de::Error::invalid_type(Unexpected::Seq, &self));
+        }
+        const EXPECTED: &str = "an array holding exactly one scalar";
+        let element = ValueVisitor {
+            unwrap_singleton: false,
+            ..self
+        };
+        let value = match tri!(visitor.next_element_seed(element)) {
+            Some(value) => value,
+            None => return Err(de::Error::invalid_length(0, &EXPECTED)),
+        };
+        let mut len = 1;
+        while tri!(visitor.next_element::<IgnoredAny>()).is_some() {
+            len += 1;
+        }
+        if len != 1 {
+            return Err(de::Error::invalid_length(len, &EXPECTED));
+        }
+        Ok(value)
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    fn visit_map<V>(self, mut visitor: V) -> Result<ValueNoObjOrArr, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        match tri!(visitor.next_key_seed(KeyClassifier)) {
+            #[cfg(feature = "arbitrary_precision")]
+            Some(KeyClass::Number) => {
+                let number: NumberFromString = tri!(visitor.next_value());
+                Ok(ValueNoObjOrArr::Number(number.value))
+            }
+            #[cfg(feature = "raw_value")]
+            Some(KeyClass::RawValue) => {
+                let value = tri!(visitor.next_value_seed(crate::raw::BoxedFromString));
+                crate::from_str(value.get()).map_err(de::Error::custom)
             }
+            Some(KeyClass::Map(_first_key)) if self.coerce_objects => {
+                tri!(visitor.next_value::<IgnoredAny>());
+                while tri!(visitor.next_entry::<IgnoredAny, IgnoredAny>()).is_some() {}
+                Ok(ValueNoObjOrArr::Null)
+            }
+            None if self.coerce_objects => Ok(ValueNoObjOrArr::Null),
+            Some(KeyClass::Map(_)) | None => Err(object_not_supported()),
         }
+    }
+}
+
+/// Strings are copied into an owned `ValueNoObjOrArr::String` whether the
+/// input hands them over borrowed, transient, or owned.
+///
+/// ```
+/// # use serde_json_extensions::ValueNoObjOrArr;
+/// #
+/// let buf = br#""plain""#.to_vec();
+/// let v: ValueNoObjOrArr = serde_json_extensions::from_slice(&buf).unwrap();
+/// drop(buf);
+/// assert_eq!(v, "plain");
+///
+/// // Escapes force the deserializer to unescape into scratch space first.
+/// let v: ValueNoObjOrArr = serde_json_extensions::from_slice(br#""tab\there""#).unwrap();
+/// assert_eq!(v, "tab\there");
+/// ```
+impl<'de> Deserialize<'de> for ValueNoObjOrArr {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<ValueNoObjOrArr, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ValueVisitor {
+            coerce_objects: false,
+            unwrap_singleton: false,
+        })
+    }
+}
 
-        deserializer.deserialize_any(ValueVisitor)
+/// A `ValueNoObjOrArr` that may also be written as an array holding just
+/// that scalar.
+///
+/// Some upstreams wrap a lone scalar in a one-element array. Deserializing
+/// into `UnwrapSingleton` accepts either form and unwraps the array, while
+/// an empty array, one with several elements, or a nested array is still an
+/// error. `ValueNoObjOrArr` itself keeps rejecting every array.
+///
+/// ```
+/// # use serde_json_extensions::ValueNoObjOrArr;
+/// use serde_json_extensions::value_no_obj_or_arr::UnwrapSingleton;
+///
+/// let v: UnwrapSingleton = serde_json_extensions::from_str("[5]").unwrap();
+/// assert_eq!(v.into_value(), ValueNoObjOrArr::from(5));
+///
+/// let v: UnwrapSingleton = serde_json_extensions::from_str(r#"[ "x" ]"#).unwrap();
+/// assert_eq!(*v.value(), "x");
+/// let v: UnwrapSingleton = serde_json_extensions::from_str("5").unwrap();
+/// assert_eq!(*v.value(), 5);
+///
+/// let err = serde_json_extensions::from_str::<UnwrapSingleton>("[]").unwrap_err();
+/// assert_eq!(err.to_string(), "invalid length 0, expected an array holding exactly one scalar at line 1 column 2");
+/// let err = serde_json_extensions::from_str::<UnwrapSingleton>("[1,2]").unwrap_err();
+/// assert_eq!(err.to_string(), "invalid length 2, expected an array holding exactly one scalar at line 1 column 5");
+/// assert!(serde_json_extensions::from_str::<UnwrapSingleton>("[[5]]").is_err());
+/// assert!(serde_json_extensions::from_str::<UnwrapSingleton>("[{}]").is_err());
+///
+/// // The default stays strict.
+/// assert!(serde_json_extensions::from_str::<ValueNoObjOrArr>("[5]").is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnwrapSingleton {
+    value: ValueNoObjOrArr,
+}
+
+impl UnwrapSingleton {
+    /// The scalar, unwrapped if it arrived in an array.
+    pub fn value(&self) -> &ValueNoObjOrArr {
+        &self.value
+    }
+
+    /// Returns the scalar, unwrapped if it arrived in an array.
+    pub fn into_value(self) -> ValueNoObjOrArr {
+        self.value
+    }
+}
+
+impl<'de> Deserialize<'de> for UnwrapSingleton {
+    fn deserialize<D>(deserializer: D) -> Result<UnwrapSingleton, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = tri!(deserializer.deserialize_any(ValueVisitor {
+            coerce_objects: false,
+            unwrap_singleton: true,
+        }));
+        Ok(UnwrapSingleton { value })
     }
 }
 
@@ -123,6 +263,278 @@ impl FromStr for ValueNoObjOrArr {
     }
 }
 
+impl ValueNoObjOrArr {
+    /// Parses a JSON scalar, refusing arrays and objects up front.
+    ///
+    /// Unlike [`FromStr`], which reports whatever the visitor makes of an
+    /// unsupported value, this checks the first token of the input and fails
+    /// with a dedicated error for each of `[` and `{`. Both are classified as
+    /// [`Category::Data`](crate::error::Category::Data).
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObjOrArr;
+    /// #
+    /// assert_eq!(ValueNoObjOrArr::from_str_strict(" 12 ").unwrap(), 12);
+    ///
+    /// let array = ValueNoObjOrArr::from_str_strict("[1]").unwrap_err();
+    /// assert!(array.is_data());
+    /// assert_eq!(array.to_string(), "arrays are not supported at line 1 column 1");
+    ///
+    /// let object = ValueNoObjOrArr::from_str_strict("  {}").unwrap_err();
+    /// assert!(object.is_data());
+    /// assert_eq!(object.to_string(), "objects are not supported at line 1 column 3");
+    /// ```
+    pub fn from_str_strict(s: &str) -> Result<ValueNoObjOrArr, Error> {
+        from_trait_strict(read::StrRead::new(s))
+    }
+
+    /// Parses a JSON scalar from bytes, refusing arrays and objects up front
+    /// exactly like [`from_str_strict`](ValueNoObjOrArr::from_str_strict).
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObjOrArr;
+    /// #
+    /// let buf: &[u8] = b" \"caf\xc3\xa9\" ";
+    /// assert_eq!(ValueNoObjOrArr::from_slice(buf).unwrap(), "café");
+    ///
+    /// let err = ValueNoObjOrArr::from_slice(b"{\"a\": 1}").unwrap_err();
+    /// assert_eq!(err.to_string(), "objects are not supported at line 1 column 1");
+    ///
+    /// assert!(ValueNoObjOrArr::from_slice(b"\"\xff\"").unwrap_err().is_syntax());
+    /// ```
+    pub fn from_slice(v: &[u8]) -> Result<ValueNoObjOrArr, Error> {
+        from_trait_strict(read::SliceRead::new(v))
+    }
+
+    /// Parses a JSON scalar from an IO stream, refusing arrays and objects up
+    /// front exactly like [`from_str_strict`](ValueNoObjOrArr::from_str_strict).
+    ///
+    /// The reader is not buffered internally; wrap it in a
+    /// [`std::io::BufReader`] when reading from a file or socket.
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObjOrArr;
+    /// use std::io::Cursor;
+    ///
+    /// let v = ValueNoObjOrArr::from_reader(Cursor::new("-1.5")).unwrap();
+    /// assert_eq!(v, -1.5);
+    ///
+    /// let err = ValueNoObjOrArr::from_reader(Cursor::new("[1]")).unwrap_err();
+    /// assert!(err.is_data());
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn from_reader<R>(rdr: R) -> Result<ValueNoObjOrArr, Error>
+    where
+        R: crate::io::Read,
+    {
+        from_trait_strict(read::IoRead::new(rdr))
+    }
+
+    /// Parses a JSON scalar, failing if it is a string longer than `max_len`
+    /// bytes once escapes are decoded.
+    ///
+    /// This bounds the size of any `String` built from untrusted input.
+    /// Arrays and objects are refused up front as in
+    /// [`from_str_strict`](ValueNoObjOrArr::from_str_strict). An overlong
+    /// string fails with a data error reporting the position where the string
+    /// ends.
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObjOrArr;
+    /// #
+    /// assert_eq!(ValueNoObjOrArr::from_str_max_string_len(r#""abc""#, 3).unwrap(), "abc");
+    /// assert_eq!(ValueNoObjOrArr::from_str_max_string_len("12345", 3).unwrap(), 12345);
+    ///
+    /// let err = ValueNoObjOrArr::from_str_max_string_len(r#""abcd""#, 3).unwrap_err();
+    /// assert!(err.is_data());
+    /// assert_eq!(err.to_string(), "string exceeds maximum length at line 1 column 6");
+    ///
+    /// // The limit is in bytes of the decoded string: `\u00e9` is "é", 2 bytes.
+    /// assert!(ValueNoObjOrArr::from_str_max_string_len(r#""\u00e9""#, 2).is_ok());
+    /// assert!(ValueNoObjOrArr::from_str_max_string_len(r#""\u00e9""#, 1).is_err());
+    /// ```
+    pub fn from_str_max_string_len(s: &str, max_len: usize) -> Result<ValueNoObjOrArr, Error> {
+        let mut de = crate::de::Deserializer::from_str(s);
+        de.set_max_string_len(max_len);
+        from_deserializer_strict(de)
+    }
+
+    /// Parses a JSON scalar, failing if it is a number written with a
+    /// fraction or an exponent.
+    ///
+    /// The check is on the text, so `1.0` and `1e3` fail even though their
+    /// values are whole, while `-0` and integers too large for `u64` are
+    /// accepted. Arrays and objects are refused as in
+    /// [`from_str_strict`](ValueNoObjOrArr::from_str_strict). For arrays of
+    /// integers, see [`ParseOptions::integers_only`].
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObjOrArr;
+    /// #
+    /// assert_eq!(ValueNoObjOrArr::from_str_integers_only("1").unwrap(), 1);
+    /// assert_eq!(ValueNoObjOrArr::from_str_integers_only("-42").unwrap(), -42);
+    /// assert_eq!(ValueNoObjOrArr::from_str_integers_only("\"1.5\"").unwrap(), "1.5");
+    ///
+    /// let err = ValueNoObjOrArr::from_str_integers_only("1.5").unwrap_err();
+    /// assert!(err.is_data());
+    /// assert_eq!(err.to_string(), "floating point numbers are not allowed at line 1 column 2");
+    ///
+    /// assert!(ValueNoObjOrArr::from_str_integers_only("1e3").is_err());
+    /// assert!(ValueNoObjOrArr::from_str_integers_only("1.0").is_err());
+    /// assert!(ValueNoObjOrArr::from_str_integers_only("18446744073709551616").is_ok());
+    /// assert!(ValueNoObjOrArr::from_str_integers_only("18446744073709551616.5").is_err());
+    /// assert!(ValueNoObjOrArr::from_str_integers_only("18446744073709551616e2").is_err());
+    /// assert!(ValueNoObjOrArr::from_str_integers_only("[1]").is_err());
+    /// ```
+    ///
+    /// [`ParseOptions::integers_only`]: crate::value_no_obj::ParseOptions::integers_only
+    pub fn from_str_integers_only(s: &str) -> Result<ValueNoObjOrArr, Error> {
+        let mut de = crate::de::Deserializer::from_str(s);
+        de.set_integers_only(true);
+        from_deserializer_strict(de)
+    }
+
+    /// Parses a JSON scalar, turning an object into `Null` instead of failing.
+    ///
+    /// This is for upstream APIs that occasionally send an object where a
+    /// scalar belongs. The object's contents are parsed, so they must still
+    /// be valid JSON, and then discarded. Arrays are still an error, as is
+    /// trailing input. [`FromStr`] and
+    /// [`from_str_strict`](ValueNoObjOrArr::from_str_strict) keep rejecting
+    /// objects.
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObjOrArr;
+    /// #
+    /// let v = ValueNoObjOrArr::from_str_lenient(r#"{"a":1}"#).unwrap();
+    /// assert_eq!(v, ValueNoObjOrArr::Null);
+    /// assert!(r#"{"a":1}"#.parse::<ValueNoObjOrArr>().is_err());
+    ///
+    /// let nested = r#"{"a": {"b": [1, 2]}, "c": null}"#;
+    /// assert_eq!(ValueNoObjOrArr::from_str_lenient(nested).unwrap(), ValueNoObjOrArr::Null);
+    /// assert_eq!(ValueNoObjOrArr::from_str_lenient("{}").unwrap(), ValueNoObjOrArr::Null);
+    ///
+    /// // Scalars parse as usual.
+    /// assert_eq!(ValueNoObjOrArr::from_str_lenient("\"x\"").unwrap(), "x");
+    /// assert_eq!(ValueNoObjOrArr::from_str_lenient("1.5").unwrap(), 1.5);
+    ///
+    /// assert!(ValueNoObjOrArr::from_str_lenient("[1]").is_err());
+    /// assert!(ValueNoObjOrArr::from_str_lenient(r#"{"a":}"#).unwrap_err().is_syntax());
+    /// ```
+    pub fn from_str_lenient(s: &str) -> Result<ValueNoObjOrArr, Error> {
+        let mut de = crate::de::Deserializer::from_str(s);
+        let value = tri!(de::Deserializer::deserialize_any(
+            &mut de,
+            ValueVisitor {
+                coerce_objects: true,
+                unwrap_singleton: false,
+            },
+        ));
+        tri!(de.end());
+        Ok(value)
+    }
+}
+
+fn from_trait_strict<'de, R>(read: R) -> Result<ValueNoObjOrArr, Error>
+where
+    R: read::Read<'de>,
+{
+    from_deserializer_strict(crate::de::Deserializer::new(read))
+}
+
+fn from_deserializer_strict<'de, R>(
+    mut de: crate::de::Deserializer<R>,
+) -> Result<ValueNoObjOrArr, Error>
+where
+    R: read::Read<'de>,
+{
+    tri!(de.reject_compound());
+    let value = tri!(ValueNoObjOrArr::deserialize(&mut de));
+    tri!(de.end());
+    Ok(value)
+}
+
+/// 128-bit integers are read with `Number::as_i128` and `Number::as_u128`,
+/// which hold the full range with the `arbitrary_precision` feature and every
+/// `i64` or `u64` without it. An integer outside the target's range fails
+/// with `NumberOutOfRange` rather than a type error; see
+/// `integer_out_of_range` for how that is told apart from a fraction.
+macro_rules! deserialize_128 {
+    ($method:ident => $as:ident, $visit:ident) => {
+        fn $method<V>(self, visitor: V) -> Result<V::Value, Error>
+        where
+            V: Visitor<'de>,
+        {
+            match self {
+                ValueNoObjOrArr::Number(n) => match n.$as() {
+                    Some(int) => visitor.$visit(int),
+                    None if integer_out_of_range(&n) => {
+                        Err(Error::syntax(ErrorCode::NumberOutOfRange, 0, 0))
+                    }
+                    None => n.deserialize_any(visitor),
+                },
+                _ => Err(self.invalid_type(&visitor)),
+            }
+        }
+    };
+}
+
+/// Whether a number that did not convert to a 128-bit integer is an integer
+/// too large for it, as opposed to a fraction or other float.
+///
+/// Without `arbitrary_precision`, integer literals beyond the 64-bit range
+/// are already parsed into floats, so integral floats outside that range are
+/// treated as such integers. With the feature, the number's text tells.
+///
+/// ```
+/// # use serde_json_extensions::ValueNoObjOrArr;
+/// use serde::Deserialize;
+///
+/// let max: ValueNoObjOrArr = u64::MAX.into();
+/// assert_eq!(u128::deserialize(&max).unwrap(), u64::MAX as u128);
+/// let min: ValueNoObjOrArr = i64::MIN.into();
+/// assert_eq!(i128::deserialize(min).unwrap(), i64::MIN as i128);
+///
+/// let negative: ValueNoObjOrArr = (-1).into();
+/// let err = u128::deserialize(&negative).unwrap_err();
+/// assert_eq!(err.to_string(), "number out of range");
+///
+/// let fraction: ValueNoObjOrArr = 0.5.into();
+/// let err = i128::deserialize(&fraction).unwrap_err();
+/// assert_eq!(err.to_string(), "invalid type: floating point `0.5`, expected i128");
+///
+/// let beyond_u128: ValueNoObjOrArr = "340282366920938463463374607431768211456".parse().unwrap();
+/// let err = u128::deserialize(&beyond_u128).unwrap_err();
+/// assert_eq!(err.to_string(), "number out of range");
+///
+/// let beyond_u64: ValueNoObjOrArr = "18446744073709551616".parse().unwrap();
+/// let i128_max: ValueNoObjOrArr = i128::MAX.to_string().parse().unwrap();
+/// let i128_min: ValueNoObjOrArr = i128::MIN.to_string().parse().unwrap();
+/// if cfg!(feature = "arbitrary_precision") {
+///     assert_eq!(u128::deserialize(&beyond_u64).unwrap(), u64::MAX as u128 + 1);
+///     assert_eq!(i128::deserialize(&i128_max).unwrap(), i128::MAX);
+///     assert_eq!(i128::deserialize(&i128_min).unwrap(), i128::MIN);
+///     assert!(i128::deserialize(&beyond_u128).is_err());
+/// } else {
+///     let err = u128::deserialize(&beyond_u64).unwrap_err();
+///     assert_eq!(err.to_string(), "number out of range");
+///     assert!(i128::deserialize(&i128_max).is_err());
+///     assert!(i128::deserialize(&i128_min).is_err());
+/// }
+/// ```
+fn integer_out_of_range(n: &Number) -> bool {
+    if !n.is_f64() {
+        return true;
+    }
+    #[cfg(not(feature = "arbitrary_precision"))]
+    if let Some(f) = n.as_f64() {
+        // Every finite float this far from zero is a whole number.
+        return !(-9223372036854775808.0..18446744073709551616.0).contains(&f);
+    }
+    false
+}
+
 macro_rules! deserialize_number {
     ($method:ident) => {
         #[cfg(not(feature = "arbitrary_precision"))]
@@ -149,6 +561,35 @@ macro_rules! deserialize_number {
     };
 }
 
+/// A `ValueNoObjOrArr` can be used directly as the input for deserializing
+/// any type whose data is a single scalar. Enums are read from a string
+/// naming a unit variant; sequences, maps and structs are rejected.
+///
+/// ```
+/// use serde::Deserialize;
+/// use serde_json_extensions::value_no_obj_or_arr::{from_value, ValueNoObjOrArr};
+///
+/// #[derive(Deserialize, Debug, PartialEq)]
+/// enum Mode {
+///     Fast,
+///     Safe,
+/// }
+///
+/// assert_eq!(from_value::<bool>(ValueNoObjOrArr::from(true)).unwrap(), true);
+/// assert_eq!(from_value::<Option<u8>>(ValueNoObjOrArr::Null).unwrap(), None);
+/// assert_eq!(from_value::<Option<u8>>(ValueNoObjOrArr::from(7)).unwrap(), Some(7));
+/// assert_eq!(from_value::<Mode>(ValueNoObjOrArr::from("Safe")).unwrap(), Mode::Safe);
+///
+/// // Deserializing from a reference borrows instead of consuming the value.
+/// let v = ValueNoObjOrArr::from("Fast");
+/// assert_eq!(Mode::deserialize(&v).unwrap(), Mode::Fast);
+/// assert_eq!(Option::<Mode>::deserialize(&v).unwrap(), Some(Mode::Fast));
+///
+/// let err = from_value::<Mode>(ValueNoObjOrArr::from(1)).unwrap_err();
+/// assert!(err.to_string().ends_with(", expected string"));
+/// assert!(from_value::<Vec<bool>>(ValueNoObjOrArr::from(true)).is_err());
+/// assert!(from_value::<std::collections::BTreeMap<String, bool>>(v).is_err());
+/// ```
 impl<'de> serde::Deserializer<'de> for ValueNoObjOrArr {
     type Error = Error;
 
@@ -172,12 +613,12 @@ impl<'de> serde::Deserializer<'de> for ValueNoObjOrArr {
     deserialize_number!(deserialize_i16);
     deserialize_number!(deserialize_i32);
     deserialize_number!(deserialize_i64);
-    deserialize_number!(deserialize_i128);
+    deserialize_128!(deserialize_i128 => as_i128, visit_i128);
     deserialize_number!(deserialize_u8);
     deserialize_number!(deserialize_u16);
     deserialize_number!(deserialize_u32);
     deserialize_number!(deserialize_u64);
-    deserialize_number!(deserialize_u128);
+    deserialize_128!(deserialize_u128 => as_u128, visit_u128);
     deserialize_number!(deserialize_f32);
     deserialize_number!(deserialize_f64);
 
@@ -207,7 +648,7 @@ impl<'de> serde::Deserializer<'de> for ValueNoObjOrArr {
             other => {
                 return Err(serde::de::Error::invalid_type(
                     other.unexpected(),
-                    &"string or map",
+                    &"string",
                 ));
             }
         };
@@ -512,12 +953,12 @@ impl<'de> serde::Deserializer<'de> for &'de ValueNoObjOrArr {
     deserialize_value_ref_number!(deserialize_i16);
     deserialize_value_ref_number!(deserialize_i32);
     deserialize_value_ref_number!(deserialize_i64);
-    deserialize_number!(deserialize_i128);
+    deserialize_128!(deserialize_i128 => as_i128, visit_i128);
     deserialize_value_ref_number!(deserialize_u8);
     deserialize_value_ref_number!(deserialize_u16);
     deserialize_value_ref_number!(deserialize_u32);
     deserialize_value_ref_number!(deserialize_u64);
-    deserialize_number!(deserialize_u128);
+    deserialize_128!(deserialize_u128 => as_u128, visit_u128);
     deserialize_value_ref_number!(deserialize_f32);
     deserialize_value_ref_number!(deserialize_f64);
 
@@ -545,7 +986,7 @@ impl<'de> serde::Deserializer<'de> for &'de ValueNoObjOrArr {
             other => {
                 return Err(serde::de::Error::invalid_type(
                     other.unexpected(),
-                    &"string or map",
+                    &"string",
                 ));
             }
         };
@@ -843,16 +1284,40 @@ impl<'de> Visitor<'de> for KeyClassifier {
 }
 
 impl ValueNoObjOrArr {
+    /// The "invalid type" error for deserializing `self` as something
+    /// described by `exp`, built from [`unexpected`](Self::unexpected).
     #[cold]
-    fn invalid_type<E>(&self, exp: &dyn Expected) -> E
+    pub(crate) fn invalid_type<E>(&self, exp: &dyn Expected) -> E
     where
         E: serde::de::Error,
     {
         serde::de::Error::invalid_type(self.unexpected(), exp)
     }
 
+    /// How `self` is described in an "invalid type" error, the same way
+    /// `serde_json::Value` describes the matching variant.
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObjOrArr;
+    /// use serde::Deserialize;
+    ///
+    /// let err = |v: ValueNoObjOrArr| Vec::<u8>::deserialize(v).unwrap_err().to_string();
+    ///
+    /// assert_eq!(err(ValueNoObjOrArr::Null), "invalid type: null, expected a sequence");
+    /// assert_eq!(err(ValueNoObjOrArr::Bool(true)), "invalid type: boolean `true`, expected a sequence");
+    /// assert_eq!(err(ValueNoObjOrArr::from("x")), "invalid type: string \"x\", expected a sequence");
+    ///
+    /// // Under `arbitrary_precision` every number is just "number".
+    /// if cfg!(feature = "arbitrary_precision") {
+    ///     assert_eq!(err(ValueNoObjOrArr::from(7)), "invalid type: number, expected a sequence");
+    /// } else {
+    ///     assert_eq!(err(ValueNoObjOrArr::from(7)), "invalid type: integer `7`, expected a sequence");
+    ///     assert_eq!(err(ValueNoObjOrArr::from(-7)), "invalid type: integer `-7`, expected a sequence");
+    ///     assert_eq!(err(ValueNoObjOrArr::from(0.5)), "invalid type: floating point `0.5`, expected a sequence");
+    /// }
+    /// ```
     #[cold]
-    fn unexpected(&self) -> Unexpected {
+    pub(crate) fn unexpected(&self) -> Unexpected {
         match self {
             ValueNoObjOrArr::Null => Unexpected::Unit,
             ValueNoObjOrArr::Bool(b) => Unexpected::Bool(*b),
@@ -1,9 +1,9 @@
 use super::ValueNoObjOrArr;
-use crate::map::Map;
+use crate::error::{Error, ErrorCode};
 use crate::number::Number;
+use crate::value_no_obj::ValueNoObj;
 use alloc::borrow::Cow;
 use alloc::string::{String, ToString};
-use alloc::vec::Vec;
 
 macro_rules! from_integer {
     ($($ty:ident)*) => {
@@ -109,6 +109,25 @@ impl From<&str> for ValueNoObjOrArr {
     }
 }
 
+impl From<char> for ValueNoObjOrArr {
+    /// Convert a `char` to a one-character `ValueNoObjOrArr::String`, the same string
+    /// that serializing the `char` produces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObjOrArr;
+    /// #
+    /// let x = ValueNoObjOrArr::from('x');
+    /// assert_eq!(x, "x");
+    /// assert_eq!(x, ValueNoObjOrArr::from("x"));
+    /// assert_eq!(ValueNoObjOrArr::from('é').as_str(), Some("é"));
+    /// ```
+    fn from(f: char) -> Self {
+        ValueNoObjOrArr::String(f.to_string())
+    }
+}
+
 impl<'a> From<Cow<'a, str>> for ValueNoObjOrArr {
     /// Convert copy-on-write string to `Value::String`.
     ///
@@ -150,101 +169,6 @@ impl From<Number> for ValueNoObjOrArr {
     }
 }
 
-impl From<Map<String, ValueNoObjOrArr>> for ValueNoObjOrArr {
-    /// Convert map (with string keys) to `Value::Object`.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use serde_json::{Map, Value};
-    ///
-    /// let mut m = Map::new();
-    /// m.insert("Lorem".to_string(), "ipsum".into());
-    /// let x: Value = m.into();
-    /// ```
-    fn from(_: Map<String, ValueNoObjOrArr>) -> Self {
-        unimplemented!()
-    }
-}
-
-impl<T: Into<ValueNoObjOrArr>> From<Vec<T>> for ValueNoObjOrArr {
-    /// Convert a `Vec` to `Value::Array`.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use serde_json::Value;
-    ///
-    /// let v = vec!["lorem", "ipsum", "dolor"];
-    /// let x: Value = v.into();
-    /// ```
-    fn from(_: Vec<T>) -> Self {
-        unimplemented!()
-    }
-}
-
-impl<T: Clone + Into<ValueNoObjOrArr>> From<&[T]> for ValueNoObjOrArr {
-    /// Convert a slice to `Value::Array`.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use serde_json::Value;
-    ///
-    /// let v: &[&str] = &["lorem", "ipsum", "dolor"];
-    /// let x: Value = v.into();
-    /// ```
-    fn from(_: &[T]) -> Self {
-        unimplemented!()
-    }
-}
-
-impl<T: Into<ValueNoObjOrArr>> FromIterator<T> for ValueNoObjOrArr {
-    /// Create a `Value::Array` by collecting an iterator of array elements.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use serde_json::Value;
-    ///
-    /// let v = std::iter::repeat(42).take(5);
-    /// let x: Value = v.collect();
-    /// ```
-    ///
-    /// ```
-    /// use serde_json::Value;
-    ///
-    /// let v: Vec<_> = vec!["lorem", "ipsum", "dolor"];
-    /// let x: Value = v.into_iter().collect();
-    /// ```
-    ///
-    /// ```
-    /// use std::iter::FromIterator;
-    /// use serde_json::Value;
-    ///
-    /// let x: Value = Value::from_iter(vec!["lorem", "ipsum", "dolor"]);
-    /// ```
-    fn from_iter<I: IntoIterator<Item = T>>(_: I) -> Self {
-        unimplemented!()
-    }
-}
-
-impl<K: Into<String>, V: Into<ValueNoObjOrArr>> FromIterator<(K, V)> for ValueNoObjOrArr {
-    /// Create a `Value::Object` by collecting an iterator of key-value pairs.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use serde_json::Value;
-    ///
-    /// let v: Vec<_> = vec![("lorem", 40), ("ipsum", 2)];
-    /// let x: Value = v.into_iter().collect();
-    /// ```
-    fn from_iter<I: IntoIterator<Item = (K, V)>>(_: I) -> Self {
-        unimplemented!()
-    }
-}
-
 impl From<()> for ValueNoObjOrArr {
     /// Convert `()` to `Value::Null`.
     ///
@@ -272,3 +196,36 @@ where
         }
     }
 }
+
+impl TryFrom<ValueNoObj> for ValueNoObjOrArr {
+    type Error = Error;
+
+    /// Narrows a `ValueNoObj` to a scalar, failing with a data error if it is
+    /// an array, since a `ValueNoObjOrArr` cannot hold one.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{value_no_obj, ValueNoObj, ValueNoObjOrArr};
+    /// #
+    /// assert_eq!(ValueNoObjOrArr::try_from(ValueNoObj::from(2.5)).unwrap(), 2.5);
+    /// let v: ValueNoObjOrArr = ValueNoObj::Null.try_into().unwrap();
+    /// assert_eq!(v, ValueNoObjOrArr::Null);
+    ///
+    /// let err = ValueNoObjOrArr::try_from(value_no_obj!([1])).unwrap_err();
+    /// assert!(err.is_data());
+    /// assert_eq!(err.to_string(), "arrays are not supported");
+    /// assert!(ValueNoObjOrArr::try_from(value_no_obj!([])).is_err());
+    ///
+    /// // Widening and narrowing a scalar gives back the same value.
+    /// let s = ValueNoObjOrArr::from("s");
+    /// assert_eq!(ValueNoObjOrArr::try_from(ValueNoObj::from(s.clone())).unwrap(), s);
+    /// ```
+    fn try_from(value: ValueNoObj) -> Result<Self, Error> {
+        match value {
+            ValueNoObj::Null => Ok(ValueNoObjOrArr::Null),
+            ValueNoObj::Bool(b) => Ok(ValueNoObjOrArr::Bool(b)),
+            ValueNoObj::Number(n) => Ok(ValueNoObjOrArr::Number(n)),
+            ValueNoObj::String(s) => Ok(ValueNoObjOrArr::String(s)),
+            ValueNoObj::Array(_) => Err(Error::syntax(ErrorCode::ArrayNotSupported, 0, 0)),
+        }
+    }
+}
@@ -91,12 +91,15 @@
 //! [from_reader]: crate::de::from_reader
 
 use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::fmt::{self, Debug, Display};
 use core::mem;
 use core::str;
 use serde::de::DeserializeOwned;
 use serde::ser::Serialize;
 
+pub use self::de::UnwrapSingleton;
 pub use self::index::Index;
 pub use self::ser::Serializer;
 use crate::error::Error;
@@ -111,6 +114,68 @@ pub use crate::raw::{to_raw_value, RawValue};
 /// Represents any valid JSON value *except* arrays and objects.
 ///
 /// See the [`serde_json::value` module documentation](self) for usage examples.
+///
+/// # No array or object conversions
+///
+/// Having no array or object variant, `ValueNoObjOrArr` implements none of
+/// `From<Vec<T>>`, `From<&[T]>`, `From<Map<String, _>>` or `FromIterator`, so
+/// attempting to build one from a collection is a compile error rather than
+/// a runtime panic. Use [`ValueNoObj`](crate::ValueNoObj) for values that may
+/// be arrays. The UI tests under `tests/ui` check the exact errors.
+///
+/// ```compile_fail,E0277
+/// # use serde_json_extensions::{Map, ValueNoObjOrArr};
+/// let v = ValueNoObjOrArr::from(Map::<String, ValueNoObjOrArr>::new());
+/// ```
+///
+/// ```compile_fail,E0277
+/// # use serde_json_extensions::ValueNoObjOrArr;
+/// let v = ValueNoObjOrArr::from(vec![1, 2, 3]);
+/// ```
+///
+/// ```compile_fail,E0277
+/// # use serde_json_extensions::ValueNoObjOrArr;
+/// let v = ValueNoObjOrArr::from(&["a", "b"][..]);
+/// ```
+///
+/// ```compile_fail,E0277
+/// # use serde_json_extensions::ValueNoObjOrArr;
+/// let v: ValueNoObjOrArr = (0..3).collect();
+/// ```
+///
+/// ```compile_fail,E0277
+/// # use serde_json_extensions::ValueNoObjOrArr;
+/// let v: ValueNoObjOrArr = vec![("k", 1)].into_iter().collect();
+/// ```
+///
+/// # Hashing
+///
+/// `ValueNoObjOrArr` implements `Hash` consistently with `Eq`, so it can be
+/// used as a `HashMap` key or collected into a `HashSet`. Numbers hash the
+/// same way they compare, so `1` and `1.0` are distinct keys.
+///
+/// ```
+/// # use serde_json_extensions::ValueNoObjOrArr;
+/// use std::collections::HashSet;
+///
+/// let values = vec![
+///     ValueNoObjOrArr::Null,
+///     ValueNoObjOrArr::from(true),
+///     ValueNoObjOrArr::from(1),
+///     ValueNoObjOrArr::from(1.5),
+///     ValueNoObjOrArr::from("a"),
+///     ValueNoObjOrArr::Null,
+///     ValueNoObjOrArr::from(true),
+///     ValueNoObjOrArr::from(1),
+///     ValueNoObjOrArr::from(1.5),
+///     ValueNoObjOrArr::from("a"),
+/// ];
+///
+/// let set: HashSet<ValueNoObjOrArr> = values.into_iter().collect();
+/// assert_eq!(set.len(), 5);
+/// assert!(set.contains(&ValueNoObjOrArr::from("a")));
+/// assert!(!set.contains(&ValueNoObjOrArr::from(false)));
+/// ```
 #[derive(Clone, Eq, PartialEq, Hash)]
 pub enum ValueNoObjOrArr {
     /// Represents a JSON null value.
@@ -156,6 +221,102 @@ impl Debug for ValueNoObjOrArr {
     }
 }
 
+/// Values are ordered first by variant, `Null < Bool < Number < String`, and
+/// then by content.
+///
+/// Booleans order `false < true` and strings order lexicographically by bytes.
+/// Numbers order by numeric value, comparing integers and floats exactly;
+/// since `1` and `1.0` are distinct values, the integer sorts first when two
+/// numbers have the same value.
+///
+/// ```
+/// # use serde_json_extensions::ValueNoObjOrArr;
+/// #
+/// let mut values = vec![
+///     ValueNoObjOrArr::from("b"),
+///     ValueNoObjOrArr::from(2.5),
+///     ValueNoObjOrArr::from(true),
+///     ValueNoObjOrArr::from(u64::MAX),
+///     ValueNoObjOrArr::Null,
+///     ValueNoObjOrArr::from(-3),
+///     ValueNoObjOrArr::from("a"),
+///     ValueNoObjOrArr::from(false),
+///     ValueNoObjOrArr::from(2),
+///     ValueNoObjOrArr::from(2.0),
+/// ];
+/// values.sort();
+///
+/// assert_eq!(values, [
+///     ValueNoObjOrArr::Null,
+///     ValueNoObjOrArr::from(false),
+///     ValueNoObjOrArr::from(true),
+///     ValueNoObjOrArr::from(-3),
+///     ValueNoObjOrArr::from(2),
+///     ValueNoObjOrArr::from(2.0),
+///     ValueNoObjOrArr::from(2.5),
+///     ValueNoObjOrArr::from(u64::MAX),
+///     ValueNoObjOrArr::from("a"),
+///     ValueNoObjOrArr::from("b"),
+/// ]);
+///
+/// assert!(ValueNoObjOrArr::from(true) < ValueNoObjOrArr::from(0));
+/// assert!(ValueNoObjOrArr::from(i64::MAX) < ValueNoObjOrArr::from(1e300));
+/// ```
+///
+/// With the `arbitrary_precision` feature, numbers are compared exactly by
+/// their decimal text, including values too large or too precise for `f64`.
+/// Numbers of equal value but different text are ordered by their text.
+///
+/// ```
+/// # use serde_json_extensions::ValueNoObjOrArr;
+/// #
+/// let n = |s: &str| s.parse::<ValueNoObjOrArr>().unwrap();
+///
+/// if cfg!(feature = "arbitrary_precision") {
+///     assert!(n("-1.0") < n("-0.99999999999999999999"));
+///     assert!(n("0.99999999999999999999") < n("1"));
+///     assert!(n("1e400") < n("1.5e400"));
+///     assert!(n("-2e400") < n("-1e400"));
+///     assert!(n("-1e400") < n("-1e-400"));
+///     assert!(n("-1e-400") < n("0"));
+///     assert!(n("0") < n("1e-400"));
+///     assert!(n("18446744073709551616") < n("18446744073709551616.5"));
+///     assert!(n("340282366920938463463374607431768211456") > n("3.4e38"));
+///
+///     // Equal in value, distinct as values, and consistently ordered.
+///     assert!(n("2") < n("2.0"));
+///     assert!(n("2.0") < n("2.00"));
+///     assert!(n("2.00") < n("2e0"));
+///     assert!(n("-0.0") < n("0.0"));
+///     assert_eq!(n("1e400").cmp(&n("1e400")), std::cmp::Ordering::Equal);
+/// }
+/// ```
+impl Ord for ValueNoObjOrArr {
+    fn cmp(&self, other: &Self) -> Ordering {
+        fn rank(value: &ValueNoObjOrArr) -> u8 {
+            match value {
+                ValueNoObjOrArr::Null => 0,
+                ValueNoObjOrArr::Bool(_) => 1,
+                ValueNoObjOrArr::Number(_) => 2,
+                ValueNoObjOrArr::String(_) => 3,
+            }
+        }
+
+        match (self, other) {
+            (ValueNoObjOrArr::Bool(a), ValueNoObjOrArr::Bool(b)) => a.cmp(b),
+            (ValueNoObjOrArr::Number(a), ValueNoObjOrArr::Number(b)) => a.total_cmp(b),
+            (ValueNoObjOrArr::String(a), ValueNoObjOrArr::String(b)) => a.cmp(b),
+            _ => rank(self).cmp(&rank(other)),
+        }
+    }
+}
+
+impl PartialOrd for ValueNoObjOrArr {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl Display for ValueNoObjOrArr {
     /// Display a JSON value as a string.
     ///
@@ -219,6 +380,55 @@ impl Display for ValueNoObjOrArr {
 }
 
 impl ValueNoObjOrArr {
+    /// Constructs a `ValueNoObjOrArr::Null`.
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObjOrArr;
+    /// #
+    /// assert!(ValueNoObjOrArr::null().is_null());
+    /// ```
+    pub fn null() -> ValueNoObjOrArr {
+        ValueNoObjOrArr::Null
+    }
+
+    /// Constructs a `ValueNoObjOrArr::Bool`.
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObjOrArr;
+    /// #
+    /// assert_eq!(ValueNoObjOrArr::bool(false), ValueNoObjOrArr::Bool(false));
+    /// ```
+    pub fn bool(b: bool) -> ValueNoObjOrArr {
+        ValueNoObjOrArr::Bool(b)
+    }
+
+    /// Constructs a `ValueNoObjOrArr::String` from anything convertible to a
+    /// `String`.
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObjOrArr;
+    /// #
+    /// assert_eq!(ValueNoObjOrArr::string("lorem"), "lorem");
+    /// assert_eq!(ValueNoObjOrArr::string(String::from("lorem")), "lorem");
+    /// ```
+    pub fn string<S: Into<String>>(s: S) -> ValueNoObjOrArr {
+        ValueNoObjOrArr::String(s.into())
+    }
+
+    /// Constructs a `ValueNoObjOrArr::Number` from anything convertible to a
+    /// [`Number`], which covers every primitive integer type.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{Number, ValueNoObjOrArr};
+    /// #
+    /// assert_eq!(ValueNoObjOrArr::number(7u8), 7);
+    /// assert_eq!(ValueNoObjOrArr::number(-7i64), -7);
+    /// assert_eq!(ValueNoObjOrArr::number(Number::from_f64(0.5).unwrap()), 0.5);
+    /// ```
+    pub fn number<N: Into<Number>>(n: N) -> ValueNoObjOrArr {
+        ValueNoObjOrArr::Number(n.into())
+    }
+
     /// Index into a JSON array or map. A string index can be used to access a
     /// value in a map, and a usize index can be used to access an element of an
     /// array.
@@ -355,16 +565,18 @@ impl ValueNoObjOrArr {
     /// None otherwise.
     ///
     /// ```
-    /// # use serde_json::{json, Number};
+    /// # use serde_json_extensions::{Number, ValueNoObjOrArr};
     /// #
-    /// let v = json!({ "a": 1, "b": 2.2, "c": -3, "d": "4" });
-    ///
-    /// assert_eq!(v["a"].as_number(), Some(&Number::from(1u64)));
-    /// assert_eq!(v["b"].as_number(), Some(&Number::from_f64(2.2).unwrap()));
-    /// assert_eq!(v["c"].as_number(), Some(&Number::from(-3i64)));
+    /// let v: ValueNoObjOrArr = 1.into();
+    /// assert_eq!(v.as_number(), Some(&Number::from(1u64)));
+    /// let v: ValueNoObjOrArr = 2.2.into();
+    /// assert_eq!(v.as_number(), Some(&Number::from_f64(2.2).unwrap()));
+    /// let v: ValueNoObjOrArr = (-3).into();
+    /// assert_eq!(v.as_number(), Some(&Number::from(-3i64)));
     ///
     /// // The string `"4"` is not a number.
-    /// assert_eq!(v["d"].as_number(), None);
+    /// let v: ValueNoObjOrArr = "4".into();
+    /// assert_eq!(v.as_number(), None);
     /// ```
     pub fn as_number(&self) -> Option<&Number> {
         match self {
@@ -373,6 +585,25 @@ impl ValueNoObjOrArr {
         }
     }
 
+    /// If the `Value` is a Number, returns the [`Number`] by value. Returns
+    /// None otherwise.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{Number, ValueNoObjOrArr};
+    /// #
+    /// let v: ValueNoObjOrArr = 7.into();
+    /// assert_eq!(v.into_number(), Some(Number::from(7)));
+    ///
+    /// assert_eq!(ValueNoObjOrArr::from("7").into_number(), None);
+    /// assert_eq!(ValueNoObjOrArr::Null.into_number(), None);
+    /// ```
+    pub fn into_number(self) -> Option<Number> {
+        match self {
+            ValueNoObjOrArr::Number(number) => Some(number),
+            _ => None,
+        }
+    }
+
     /// Returns true if the `Value` is an integer between `i64::MIN` and
     /// `i64::MAX`.
     ///
@@ -490,6 +721,120 @@ impl ValueNoObjOrArr {
         }
     }
 
+    /// If the `ValueNoObjOrArr` is an integer, represent it as i128 if possible. Returns
+    /// None otherwise.
+    ///
+    /// Integers outside the `i64`/`u64` range are only preserved when the
+    /// `arbitrary_precision` feature is enabled; see [`Number::as_i128`].
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObjOrArr;
+    /// #
+    /// let v: ValueNoObjOrArr = "-18446744073709551616".parse().unwrap();
+    /// if cfg!(feature = "arbitrary_precision") {
+    ///     assert_eq!(v.as_i128(), Some(-(1_i128 << 64)));
+    /// } else {
+    ///     assert_eq!(v.as_i128(), None);
+    /// }
+    ///
+    /// assert_eq!(ValueNoObjOrArr::from(-64).as_i128(), Some(-64));
+    /// assert_eq!(ValueNoObjOrArr::from("64").as_i128(), None);
+    /// ```
+    pub fn as_i128(&self) -> Option<i128> {
+        match self {
+            ValueNoObjOrArr::Number(n) => n.as_i128(),
+            _ => None,
+        }
+    }
+
+    /// If the `ValueNoObjOrArr` is an integer, represent it as u128 if possible. Returns
+    /// None otherwise.
+    ///
+    /// Integers above `u64::MAX` are only preserved when the
+    /// `arbitrary_precision` feature is enabled; see [`Number::as_u128`].
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObjOrArr;
+    /// #
+    /// let v: ValueNoObjOrArr = "18446744073709551616".parse().unwrap();
+    /// if cfg!(feature = "arbitrary_precision") {
+    ///     assert_eq!(v.as_u128(), Some(1_u128 << 64));
+    /// } else {
+    ///     assert_eq!(v.as_u128(), None);
+    /// }
+    ///
+    /// assert_eq!(ValueNoObjOrArr::from(64).as_u128(), Some(64));
+    /// assert_eq!(ValueNoObjOrArr::from(-64).as_u128(), None);
+    /// ```
+    pub fn as_u128(&self) -> Option<u128> {
+        match self {
+            ValueNoObjOrArr::Number(n) => n.as_u128(),
+            _ => None,
+        }
+    }
+
+    /// Builds a number from an `i128`, falling back to the nearest `f64`
+    /// when it does not fit in `i64` or `u64`.
+    ///
+    /// **This is lossy**: an `f64` keeps only 53 significant bits, so beyond
+    /// the 64-bit range the integer is rounded and distinct inputs can give
+    /// equal results. Serializing an out-of-range `i128`
+    /// fails with "number out of range" instead; use this only when an
+    /// approximate value is better than an error. With the
+    /// `arbitrary_precision` feature, every `i128` is stored exactly.
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObjOrArr;
+    /// #
+    /// assert_eq!(ValueNoObjOrArr::from_i128_lossy(-5), -5);
+    /// assert_eq!(ValueNoObjOrArr::from_i128_lossy(u64::MAX as i128), u64::MAX);
+    ///
+    /// let big = ValueNoObjOrArr::from_i128_lossy(-(u64::MAX as i128) - 1);
+    /// if cfg!(feature = "arbitrary_precision") {
+    ///     assert_eq!(big.as_i128(), Some(-(u64::MAX as i128) - 1));
+    /// } else {
+    ///     assert!(big.is_f64());
+    ///     assert_eq!(big.as_f64(), Some(-18446744073709551616.0));
+    /// }
+    /// ```
+    pub fn from_i128_lossy(n: i128) -> ValueNoObjOrArr {
+        ValueNoObjOrArr::Number(Number::from_i128_lossy(n))
+    }
+
+    /// Builds a number from a `u128`, falling back to the nearest `f64`
+    /// when it does not fit in `u64`.
+    ///
+    /// **This is lossy** in the same way as
+    /// [`from_i128_lossy`](ValueNoObjOrArr::from_i128_lossy): for example
+    /// `u64::MAX + 1` and `u64::MAX + 2` both become
+    /// `18446744073709551616.0`. With the `arbitrary_precision` feature,
+    /// every `u128` is stored exactly.
+    ///
+    /// ```
+    /// # use serde_json_extensions::ValueNoObjOrArr;
+    /// #
+    /// assert_eq!(ValueNoObjOrArr::from_u128_lossy(u64::MAX as u128), u64::MAX);
+    ///
+    /// let one_past = ValueNoObjOrArr::from_u128_lossy(u64::MAX as u128 + 1);
+    /// let two_past = ValueNoObjOrArr::from_u128_lossy(u64::MAX as u128 + 2);
+    /// if cfg!(feature = "arbitrary_precision") {
+    ///     assert_eq!(one_past.as_u128(), Some(u64::MAX as u128 + 1));
+    ///     assert_ne!(one_past, two_past);
+    /// } else {
+    ///     assert!(one_past.is_f64());
+    ///     assert_eq!(one_past.as_f64(), Some(18446744073709551616.0));
+    ///     assert_eq!(one_past, two_past);
+    ///     assert_eq!(one_past.to_string(), "1.8446744073709552e19");
+    /// }
+    ///
+    /// // Serializing still refuses without `arbitrary_precision`.
+    /// let strict = serde_json_extensions::value_no_obj_or_arr::to_value(u64::MAX as u128 + 1);
+    /// assert_eq!(strict.is_ok(), cfg!(feature = "arbitrary_precision"));
+    /// ```
+    pub fn from_u128_lossy(n: u128) -> ValueNoObjOrArr {
+        ValueNoObjOrArr::Number(Number::from_u128_lossy(n))
+    }
+
     /// If the `Value` is a number, represent it as f64 if possible. Returns
     /// None otherwise.
     ///
@@ -695,29 +1040,35 @@ impl ValueNoObjOrArr {
     }
 }
 
-/// The default value is `Value::Null`.
+/// The default value is `ValueNoObjOrArr::Null`.
 ///
-/// This is useful for handling omitted `Value` fields when deserializing.
+/// This is useful for handling omitted `ValueNoObjOrArr` fields when deserializing,
+/// for `#[derive(Default)]` structs, and for [`mem::take`].
 ///
 /// # Examples
 ///
 /// ```
 /// # use serde::Deserialize;
-/// use serde_json::Value;
+/// use serde_json_extensions::ValueNoObjOrArr;
 ///
-/// #[derive(Deserialize)]
+/// #[derive(Deserialize, Default)]
 /// struct Settings {
 ///     level: i32,
 ///     #[serde(default)]
-///     extras: Value,
+///     extras: ValueNoObjOrArr,
 /// }
 ///
-/// # fn try_main() -> Result<(), serde_json::Error> {
+/// # fn try_main() -> Result<(), serde_json_extensions::Error> {
 /// let data = r#" { "level": 42 } "#;
-/// let s: Settings = serde_json::from_str(data)?;
+/// let s: Settings = serde_json_extensions::from_str(data)?;
 ///
 /// assert_eq!(s.level, 42);
-/// assert_eq!(s.extras, Value::Null);
+/// assert_eq!(s.extras, ValueNoObjOrArr::Null);
+/// assert_eq!(Settings::default().extras, ValueNoObjOrArr::default());
+///
+/// let mut v = ValueNoObjOrArr::from("taken");
+/// assert_eq!(std::mem::take(&mut v), "taken");
+/// assert_eq!(v, ValueNoObjOrArr::Null);
 /// #
 /// #     Ok(())
 /// # }
@@ -736,58 +1087,57 @@ mod index;
 mod partial_eq;
 mod ser;
 
-/// Convert a `T` into `serde_json::Value` which is an enum that can represent
-/// any valid JSON data.
+/// Convert a `T` into `ValueNoObjOrArr`, a JSON scalar.
 ///
 /// # Example
 ///
 /// ```
 /// use serde::Serialize;
-/// use serde_json::json;
-/// use std::error::Error;
+/// use serde_json_extensions::value_no_obj_or_arr::{to_value, ValueNoObjOrArr};
 ///
 /// #[derive(Serialize)]
-/// struct User {
-///     fingerprint: String,
-///     location: String,
-/// }
-///
-/// fn compare_json_values() -> Result<(), Box<dyn Error>> {
-///     let u = User {
-///         fingerprint: "0xF9BA143B95FF6D82".to_owned(),
-///         location: "Menlo Park, CA".to_owned(),
-///     };
+/// struct Fingerprint(String);
 ///
-///     // The type of `expected` is `serde_json::Value`
-///     let expected = json!({
-///         "fingerprint": "0xF9BA143B95FF6D82",
-///         "location": "Menlo Park, CA",
-///     });
+/// #[derive(Serialize)]
+/// enum Region {
+///     Americas,
+///     Europe,
+/// }
 ///
-///     let v = serde_json::to_value(u).unwrap();
-///     assert_eq!(v, expected);
+/// let v = to_value(Fingerprint("0xF9BA143B95FF6D82".to_owned())).unwrap();
+/// assert_eq!(v, ValueNoObjOrArr::from("0xF9BA143B95FF6D82"));
 ///
-///     Ok(())
-/// }
-/// #
-/// # compare_json_values().unwrap();
+/// assert_eq!(to_value(Region::Europe).unwrap(), "Europe");
+/// assert_eq!(to_value(Some(1.5)).unwrap(), 1.5);
+/// assert_eq!(to_value(()).unwrap(), ValueNoObjOrArr::Null);
 /// ```
 ///
 /// # Errors
 ///
-/// This conversion can fail if `T`'s implementation of `Serialize` decides to
-/// fail, or if `T` contains a map with non-string keys.
+/// This conversion fails if `T`'s implementation of `Serialize` decides to
+/// fail, or if `T` serializes as anything other than a scalar: sequences,
+/// tuples, maps, structs, and enum variants carrying data are all rejected.
 ///
 /// ```
+/// use serde::Serialize;
+/// use serde_json_extensions::value_no_obj_or_arr::to_value;
 /// use std::collections::BTreeMap;
 ///
-/// fn main() {
-///     // The keys in this map are vectors, not strings.
-///     let mut map = BTreeMap::new();
-///     map.insert(vec![32, 64], "x86");
+/// #[derive(Serialize)]
+/// struct User {
+///     location: String,
+/// }
 ///
-///     println!("{}", serde_json::to_value(map).unwrap_err());
+/// #[derive(Serialize)]
+/// enum Shape {
+///     Circle(f64),
 /// }
+///
+/// assert!(to_value(vec![1, 2]).is_err());
+/// assert!(to_value((1, "a")).is_err());
+/// assert!(to_value(BTreeMap::<String, i32>::new()).is_err());
+/// assert!(to_value(User { location: "Menlo Park, CA".to_owned() }).is_err());
+/// assert!(to_value(Shape::Circle(1.0)).is_err());
 /// ```
 // Taking by value is more friendly to iterator adapters, option and result
 // consumers, etc. See https://github.com/serde-rs/json/pull/149.
@@ -798,44 +1148,120 @@ where
     value.serialize(Serializer)
 }
 
-/// Interpret a `serde_json::Value` as an instance of type `T`.
+/// Interpret a `ValueNoObjOrArr` as an instance of type `T`.
 ///
 /// # Example
 ///
 /// ```
 /// use serde::Deserialize;
-/// use serde_json::json;
+/// use serde_json_extensions::value_no_obj_or_arr::{from_value, ValueNoObjOrArr};
 ///
-/// #[derive(Deserialize, Debug)]
-/// struct User {
-///     fingerprint: String,
-///     location: String,
+/// #[derive(Deserialize, Debug, PartialEq)]
+/// struct Fingerprint(String);
+///
+/// #[derive(Deserialize, Debug, PartialEq)]
+/// enum Region {
+///     Americas,
+///     Europe,
 /// }
 ///
-/// fn main() {
-///     // The type of `j` is `serde_json::Value`
-///     let j = json!({
-///         "fingerprint": "0xF9BA143B95FF6D82",
-///         "location": "Menlo Park, CA"
-///     });
+/// let v = ValueNoObjOrArr::from("0xF9BA143B95FF6D82");
+/// let f: Fingerprint = from_value(v).unwrap();
+/// assert_eq!(f, Fingerprint("0xF9BA143B95FF6D82".to_owned()));
 ///
-///     let u: User = serde_json::from_value(j).unwrap();
-///     println!("{:#?}", u);
-/// }
+/// let r: Region = from_value(ValueNoObjOrArr::from("Americas")).unwrap();
+/// assert_eq!(r, Region::Americas);
 /// ```
 ///
 /// # Errors
 ///
-/// This conversion can fail if the structure of the Value does not match the
-/// structure expected by `T`, for example if `T` is a struct type but the Value
-/// contains something other than a JSON map. It can also fail if the structure
-/// is correct but `T`'s implementation of `Deserialize` decides that something
-/// is wrong with the data, for example required struct fields are missing from
-/// the JSON map or some number is too big to fit in the expected primitive
+/// This conversion can fail if the Value does not match the type expected by
+/// `T`, for example if `T` is a struct or a sequence, or if the Value is a
+/// string that names no variant of an enum `T`. It can also fail if `T`'s
+/// implementation of `Deserialize` decides that something is wrong with the
+/// data, for example some number is too big to fit in the expected primitive
 /// type.
+///
+/// ```
+/// # use serde::Deserialize;
+/// # use serde_json_extensions::value_no_obj_or_arr::{from_value, ValueNoObjOrArr};
+/// #
+/// # #[derive(Deserialize, Debug)]
+/// # enum Region {
+/// #     Americas,
+/// #     Europe,
+/// # }
+/// #
+/// assert!(from_value::<Region>(ValueNoObjOrArr::from("Asia")).is_err());
+/// assert!(from_value::<u8>(ValueNoObjOrArr::from(256)).is_err());
+/// assert!(from_value::<Vec<u8>>(ValueNoObjOrArr::from(1)).is_err());
+/// ```
 pub fn from_value<T>(value: ValueNoObjOrArr) -> Result<T, Error>
 where
     T: DeserializeOwned,
 {
     T::deserialize(value)
 }
+
+/// Serialize a `ValueNoObjOrArr` as JSON into the I/O stream.
+///
+/// ```
+/// # use serde_json_extensions::ValueNoObjOrArr;
+/// #
+/// let value = ValueNoObjOrArr::from("a \"quoted\" word");
+///
+/// let mut buf = Vec::new();
+/// serde_json_extensions::value_no_obj_or_arr::to_writer(&mut buf, &value).unwrap();
+/// assert_eq!(buf, br#""a \"quoted\" word""#);
+/// assert_eq!(ValueNoObjOrArr::from_slice(&buf).unwrap(), value);
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn to_writer<W>(writer: W, value: &ValueNoObjOrArr) -> Result<(), Error>
+where
+    W: io::Write,
+{
+    crate::ser::to_writer(writer, value)
+}
+
+/// Serialize a `ValueNoObjOrArr` as pretty-printed JSON into the I/O stream.
+///
+/// A scalar has no structure to indent, so this writes the same bytes as
+/// [`to_writer`]. It exists for symmetry with `ValueNoObj`.
+///
+/// ```
+/// # use serde_json_extensions::ValueNoObjOrArr;
+/// #
+/// let mut buf = Vec::new();
+/// serde_json_extensions::value_no_obj_or_arr::to_writer_pretty(&mut buf, &ValueNoObjOrArr::Null).unwrap();
+/// assert_eq!(buf, b"null");
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn to_writer_pretty<W>(writer: W, value: &ValueNoObjOrArr) -> Result<(), Error>
+where
+    W: io::Write,
+{
+    crate::ser::to_writer_pretty(writer, value)
+}
+
+/// Serialize a `ValueNoObjOrArr` as a JSON byte vector.
+///
+/// ```
+/// # use serde_json_extensions::ValueNoObjOrArr;
+/// #
+/// let value = ValueNoObjOrArr::from(-12.5);
+/// let buf = serde_json_extensions::value_no_obj_or_arr::to_vec(&value).unwrap();
+/// assert_eq!(buf, b"-12.5");
+/// assert_eq!(ValueNoObjOrArr::from_slice(&buf).unwrap(), value);
+/// ```
+pub fn to_vec(value: &ValueNoObjOrArr) -> Result<Vec<u8>, Error> {
+    crate::ser::to_vec(value)
+}
+
+/// Serialize a `ValueNoObjOrArr` as a pretty-printed JSON byte vector.
+///
+/// Like [`to_writer_pretty`], this produces the same bytes as [`to_vec`].
+pub fn to_vec_pretty(value: &ValueNoObjOrArr) -> Result<Vec<u8>, Error> {
+    crate::ser::to_vec_pretty(value)
+}
@@ -1,3 +1,43 @@
+//! `ValueNoObjOrArr` compares directly with strings, booleans and the
+//! numeric primitives, in either order. A comparison only succeeds when the
+//! variant matches: a number never equals a string, and integers compare
+//! with floats only through the float impls.
+//!
+//! ```
+//! # use serde_json_extensions::ValueNoObjOrArr;
+//! #
+//! let s = ValueNoObjOrArr::from("hello");
+//! assert!(s == "hello");
+//! assert!("hello" == s);
+//! assert!(s == *"hello");
+//! assert!(s == String::from("hello"));
+//! assert!(String::from("hello") == s);
+//! assert!(s != "world");
+//!
+//! let n = ValueNoObjOrArr::from(42);
+//! assert!(n == 42i8 && n == 42i16 && n == 42i32 && n == 42i64 && n == 42isize);
+//! assert!(n == 42u8 && n == 42u16 && n == 42u32 && n == 42u64 && n == 42usize);
+//! assert!(42 == n);
+//! assert!(n == 42.0f64 && n == 42.0f32);
+//! assert!(n != 43);
+//!
+//! let neg = ValueNoObjOrArr::from(-1);
+//! assert!(neg == -1i64);
+//! assert!(neg != u64::MAX);
+//!
+//! let f = ValueNoObjOrArr::from(0.5);
+//! assert!(f == 0.5f64 && f == 0.5f32);
+//! assert!(f != 0);
+//!
+//! let b = ValueNoObjOrArr::from(true);
+//! assert!(b == true);
+//! assert!(false != b);
+//!
+//! assert!(ValueNoObjOrArr::Null != 0);
+//! assert!(ValueNoObjOrArr::Null != "");
+//! assert!(ValueNoObjOrArr::from("42") != 42);
+//! ```
+
 use super::ValueNoObjOrArr;
 use alloc::string::String;
 
@@ -6,10 +6,39 @@ use serde::de;
 use serde::de::Unexpected;
 use serde::ser::{Impossible, Serialize};
 
-use crate::error::{Error, ErrorCode, Result};
+use crate::error::{object_not_supported, Error, ErrorCode, Result};
 use crate::map::Map;
 use crate::ValueNoObjOrArr;
 
+/// `Null` goes through `serialize_unit`, so it is always written as an
+/// explicit `null`, whether on its own, inside a sequence, or as a struct
+/// field.
+///
+/// ```
+/// use serde::Serialize;
+/// use serde_json_extensions::ValueNoObjOrArr;
+///
+/// #[derive(Serialize)]
+/// struct Reading {
+///     sensor: &'static str,
+///     value: ValueNoObjOrArr,
+/// }
+///
+/// let s = serde_json_extensions::to_string(&ValueNoObjOrArr::Null).unwrap();
+/// assert_eq!(s, "null");
+///
+/// let list = vec![ValueNoObjOrArr::Null, ValueNoObjOrArr::Null];
+/// assert_eq!(serde_json_extensions::to_string(&list).unwrap(), "[null,null]");
+///
+/// let reading = Reading {
+///     sensor: "t1",
+///     value: ValueNoObjOrArr::Null,
+/// };
+/// assert_eq!(
+///     serde_json_extensions::to_string(&reading).unwrap(),
+///     r#"{"sensor":"t1","value":null}"#,
+/// );
+/// ```
 impl Serialize for ValueNoObjOrArr {
     #[inline]
     fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
@@ -147,6 +176,25 @@ impl serde::Serializer for Serializer {
         Ok(ValueNoObjOrArr::from(float))
     }
 
+    /// Serialize a `char` as a single-character `ValueNoObjOrArr::String`,
+    /// encoded as UTF-8 like any other Rust string.
+    ///
+    /// ```
+    /// use serde_json_extensions::{from_value, to_value, ValueNoObjOrArr};
+    ///
+    /// // Multi-byte and surrogate-range-adjacent code points.
+    /// for c in ['x', 'é', '😀', '\u{D7FF}', '\u{E000}', '\u{10FFFF}'] {
+    ///     let value = to_value(c).unwrap();
+    ///     assert_eq!(value, ValueNoObjOrArr::String(c.to_string()));
+    ///     assert_eq!(from_value::<char>(value).unwrap(), c);
+    /// }
+    ///
+    /// // A `char` map key is accepted by the key serializer, so the error is
+    /// // the object rejection rather than "key must be a string".
+    /// let map = std::collections::BTreeMap::from([('😀', 1)]);
+    /// let err = to_value(map).unwrap_err();
+    /// assert!(err.is_data());
+    /// ```
     #[inline]
     fn serialize_char(self, value: char) -> Result<ValueNoObjOrArr> {
         let mut s = String::new();
@@ -159,6 +207,27 @@ impl serde::Serializer for Serializer {
         Ok(ValueNoObjOrArr::String(value.to_owned()))
     }
 
+    /// Byte buffers are rejected rather than encoded.
+    ///
+    /// `ValueNoObj` represents bytes as an array of numbers, which has no
+    /// counterpart among the scalar variants, and picking a text encoding
+    /// such as base64 here would silently change the data's shape. Callers
+    /// that want a string should encode the bytes themselves before
+    /// serializing.
+    ///
+    /// ```
+    /// # use serde_json_extensions::value_no_obj_or_arr::{to_value, ValueNoObjOrArr};
+    /// #
+    /// let err = to_value(serde_bytes::Bytes::new(b"abc")).unwrap_err();
+    /// assert!(err.is_data());
+    ///
+    /// // An empty buffer is still a byte buffer.
+    /// assert!(to_value(serde_bytes::Bytes::new(b"")).is_err());
+    ///
+    /// // A pre-encoded string goes through untouched.
+    /// let encoded = "YWJj";
+    /// assert_eq!(to_value(encoded).unwrap(), ValueNoObjOrArr::String(encoded.to_owned()));
+    /// ```
     fn serialize_bytes(self, value: &[u8]) -> Result<ValueNoObjOrArr> {
         Err(serde::de::Error::invalid_type(
             serde::de::Unexpected::Bytes(value),
@@ -204,10 +273,7 @@ impl serde::Serializer for Serializer {
     where
         T: ?Sized + Serialize,
     {
-        Err(serde::de::Error::invalid_type(
-            serde::de::Unexpected::NewtypeStruct,
-            &"must provide non-array | non-object",
-        ))
+        Err(object_not_supported())
     }
 
     #[inline]
@@ -215,6 +281,21 @@ impl serde::Serializer for Serializer {
         self.serialize_unit()
     }
 
+    /// `Some` is transparent, as in `serde_json`, so nested options collapse
+    /// to a single `null` or to the innermost value.
+    ///
+    /// ```
+    /// # use serde_json_extensions::{to_value, ValueNoObjOrArr};
+    /// #
+    /// assert_eq!(to_value(Some(None::<i32>)).unwrap(), ValueNoObjOrArr::Null);
+    /// assert_eq!(to_value(None::<Option<i32>>).unwrap(), ValueNoObjOrArr::Null);
+    /// assert_eq!(to_value(Some(Some(1))).unwrap(), ValueNoObjOrArr::from(1));
+    /// assert_eq!(to_value(Some(Some(Some("x")))).unwrap(), ValueNoObjOrArr::from("x"));
+    ///
+    /// // The same text as `serde_json` writes for these values.
+    /// assert_eq!(to_value(Some(None::<i32>)).unwrap().to_string(), "null");
+    /// assert_eq!(to_value(Some(Some(1))).unwrap().to_string(), "1");
+    /// ```
     #[inline]
     fn serialize_some<T>(self, value: &T) -> Result<ValueNoObjOrArr>
     where
@@ -279,12 +360,36 @@ impl serde::Serializer for Serializer {
         _variant: &'static str,
         _len: usize,
     ) -> Result<Self::SerializeStructVariant> {
-        Err(de::Error::invalid_type(
-            Unexpected::NewtypeStruct,
-            &"`Object` isn't supported",
-        ))
-    }
-
+        Err(object_not_supported())
+    }
+
+    /// Produces a `String` variant from the value's `Display` output, so
+    /// types that serialize through `collect_str`, such as date and time
+    /// types, convert without an intermediate `String` of their own.
+    ///
+    /// ```
+    /// use serde::{Serialize, Serializer};
+    /// use serde_json_extensions::value_no_obj_or_arr::to_value;
+    /// # use serde_json_extensions::ValueNoObjOrArr;
+    /// use std::fmt;
+    ///
+    /// struct Date(u16, u8, u8);
+    ///
+    /// impl fmt::Display for Date {
+    ///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    ///         write!(f, "{:04}-{:02}-{:02}", self.0, self.1, self.2)
+    ///     }
+    /// }
+    ///
+    /// impl Serialize for Date {
+    ///     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    ///         serializer.collect_str(self)
+    ///     }
+    /// }
+    ///
+    /// let v = to_value(Date(2024, 1, 2)).unwrap();
+    /// assert_eq!(v, ValueNoObjOrArr::String("2024-01-02".to_owned()));
+    /// ```
     fn collect_str<T>(self, value: &T) -> Result<ValueNoObjOrArr>
     where
         T: ?Sized + Display,
@@ -372,17 +477,11 @@ impl serde::ser::SerializeTupleVariant for SerializeTupleVariant {
     where
         T: ?Sized + Serialize,
     {
-        Err(serde::de::Error::invalid_type(
-            serde::de::Unexpected::Map,
-            &"must provide non-array | non-object",
-        ))
+        Err(object_not_supported())
     }
 
     fn end(self) -> Result<ValueNoObjOrArr> {
-        Err(serde::de::Error::invalid_type(
-            serde::de::Unexpected::Map,
-            &"must provide non-array | non-object",
-        ))
+        Err(object_not_supported())
     }
 }
 
@@ -411,10 +510,7 @@ impl serde::ser::SerializeMap for SerializeMap {
         T: ?Sized + Serialize,
     {
         match self {
-            SerializeMap::Map { .. } => Err(serde::de::Error::invalid_type(
-                Unexpected::Map,
-                &"Object aren't supported",
-            )),
+            SerializeMap::Map { .. } => Err(object_not_supported()),
             #[cfg(feature = "arbitrary_precision")]
             SerializeMap::Number { .. } => unreachable!(),
             #[cfg(feature = "raw_value")]
@@ -424,10 +520,7 @@ impl serde::ser::SerializeMap for SerializeMap {
 
     fn end(self) -> Result<ValueNoObjOrArr> {
         match self {
-            SerializeMap::Map { .. } => Err(serde::de::Error::invalid_type(
-                serde::de::Unexpected::Map,
-                &"must provide non-array | non-object",
-            )),
+            SerializeMap::Map { .. } => Err(object_not_supported()),
             #[cfg(feature = "arbitrary_precision")]
             SerializeMap::Number { .. } => unreachable!(),
             #[cfg(feature = "raw_value")]
@@ -684,17 +777,11 @@ impl serde::ser::SerializeStructVariant for SerializeStructVariant {
     where
         T: ?Sized + Serialize,
     {
-        Err(serde::de::Error::invalid_type(
-            serde::de::Unexpected::Map,
-            &"must provide non-array | non-object",
-        ))
+        Err(object_not_supported())
     }
 
     fn end(self) -> Result<ValueNoObjOrArr> {
-        Err(serde::de::Error::invalid_type(
-            serde::de::Unexpected::Map,
-            &"must provide non-array | non-object",
-        ))
+        Err(object_not_supported())
     }
 }
 
@@ -869,6 +956,40 @@ impl serde::ser::Serializer for NumberValueEmitter {
     }
 }
 
+/// Turns the text captured by a serialized `RawValue` back into a
+/// `ValueNoObjOrArr`, so `to_value` accepts raw scalars just like
+/// `from_str` does. Together with `to_raw_value`, a scalar makes the round
+/// trip through `RawValue` in both directions.
+///
+/// ```
+/// use serde_json_extensions::value_no_obj_or_arr::{to_raw_value, to_value, RawValue};
+/// use serde_json_extensions::ValueNoObjOrArr;
+///
+/// for input in ["12", "-0.5", r#""a\u0062""#, "true", "null"] {
+///     let raw: Box<RawValue> = serde_json_extensions::from_str(input).unwrap();
+///
+///     // The raw text survives untouched on its way back out.
+///     assert_eq!(serde_json_extensions::to_string(&raw).unwrap(), input);
+///
+///     let from_raw: ValueNoObjOrArr = serde_json_extensions::from_str(raw.get()).unwrap();
+///     assert_eq!(to_value(&raw).unwrap(), from_raw);
+///
+///     // Re-serializing the scalar into a RawValue gives the same bytes
+///     // whenever the input was already in canonical form.
+///     let back = to_raw_value(&from_raw).unwrap();
+///     let canonical = serde_json_extensions::to_string(&from_raw).unwrap();
+///     assert_eq!(back.get(), canonical);
+/// }
+///
+/// let raw = to_raw_value(&ValueNoObjOrArr::from(-0.5)).unwrap();
+/// assert_eq!(raw.get(), "-0.5");
+///
+/// // Raw arrays and objects are still refused.
+/// let raw: Box<RawValue> = serde_json_extensions::from_str("[1]").unwrap();
+/// assert!(to_value(&raw).is_err());
+/// let raw: Box<RawValue> = serde_json_extensions::from_str("{}").unwrap();
+/// assert!(to_value(&raw).is_err());
+/// ```
 #[cfg(feature = "raw_value")]
 struct RawValueEmitter;
 
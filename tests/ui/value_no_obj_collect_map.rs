@@ -0,0 +1,7 @@
+use serde_json_extensions::ValueNoObj;
+use std::collections::BTreeMap;
+
+fn main() {
+    let map = BTreeMap::from([("a", 1)]);
+    let _: ValueNoObj = map.into_iter().collect();
+}
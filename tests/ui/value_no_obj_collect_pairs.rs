@@ -0,0 +1,5 @@
+use serde_json_extensions::ValueNoObj;
+
+fn main() {
+    let _: ValueNoObj = vec![("k", 1)].into_iter().collect();
+}
@@ -0,0 +1,5 @@
+use serde_json_extensions::value_no_obj::{Map, ValueNoObj};
+
+fn main() {
+    let _ = ValueNoObj::from(Map::<String, ValueNoObj>::new());
+}
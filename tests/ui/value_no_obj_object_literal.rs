@@ -0,0 +1,5 @@
+use serde_json_extensions::value_no_obj;
+
+fn main() {
+    value_no_obj!({ "code": 200 });
+}
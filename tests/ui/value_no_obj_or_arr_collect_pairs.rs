@@ -0,0 +1,5 @@
+use serde_json_extensions::ValueNoObjOrArr;
+
+fn main() {
+    let _: ValueNoObjOrArr = vec![("k", 1)].into_iter().collect();
+}
@@ -0,0 +1,5 @@
+use serde_json_extensions::{Map, ValueNoObjOrArr};
+
+fn main() {
+    let _ = ValueNoObjOrArr::from(Map::<String, ValueNoObjOrArr>::new());
+}
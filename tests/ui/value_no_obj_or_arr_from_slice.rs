@@ -0,0 +1,5 @@
+use serde_json_extensions::ValueNoObjOrArr;
+
+fn main() {
+    let _ = ValueNoObjOrArr::from(&["a", "b"][..]);
+}
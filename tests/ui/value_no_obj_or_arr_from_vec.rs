@@ -0,0 +1,5 @@
+use serde_json_extensions::ValueNoObjOrArr;
+
+fn main() {
+    let _ = ValueNoObjOrArr::from(vec![1, 2, 3]);
+}
@@ -0,0 +1,72 @@
+//! Whatever the input, parsing a `ValueNoObjOrArr` succeeds exactly when the
+//! top-level value is a scalar. This checks that against a few thousand
+//! generated documents mixing scalars, arrays and objects at every depth.
+
+use serde_json_extensions::ValueNoObjOrArr;
+
+/// A small xorshift generator, so the documents are reproducible.
+struct Rng(u64);
+
+impl Rng {
+    fn below(&mut self, n: u64) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0 % n
+    }
+}
+
+fn generate(rng: &mut Rng, depth: u32, out: &mut String) {
+    let kinds = if depth == 0 { 4 } else { 6 };
+    match rng.below(kinds) {
+        0 => out.push_str(["null", "true", "false"][rng.below(3) as usize]),
+        1 => out
+            .push_str(["0", "-1", "2.5e-3", "18446744073709551616", "-0.0"][rng.below(5) as usize]),
+        2 => out.push_str([r#""""#, r#""a\"b""#, r#""\u00e9""#, r#""{}""#][rng.below(4) as usize]),
+        3 => out.push_str(" 7 "),
+        4 => {
+            out.push('[');
+            for i in 0..rng.below(3) {
+                if i > 0 {
+                    out.push(',');
+                }
+                generate(rng, depth - 1, out);
+            }
+            out.push(']');
+        }
+        _ => {
+            out.push('{');
+            for i in 0..rng.below(3) {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(
+                    ["\"k\":", "\"$serde_json::private::Number\":"][rng.below(2) as usize],
+                );
+                generate(rng, depth - 1, out);
+            }
+            out.push('}');
+        }
+    }
+}
+
+#[test]
+fn parses_exactly_the_scalars() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..5_000 {
+        let mut input = String::new();
+        generate(&mut rng, 3, &mut input);
+        let is_scalar = !input.starts_with('[') && !input.starts_with('{');
+
+        let parsed = serde_json_extensions::from_str::<ValueNoObjOrArr>(&input);
+        assert_eq!(parsed.is_ok(), is_scalar, "{}", input);
+        let strict = ValueNoObjOrArr::from_str_strict(&input);
+        assert_eq!(strict.is_ok(), is_scalar, "{}", input);
+        if input.starts_with('{') {
+            assert!(strict
+                .unwrap_err()
+                .to_string()
+                .starts_with("objects are not supported"));
+        }
+    }
+}